@@ -15,24 +15,29 @@
  */
 
 use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
 };
 
-use dyn_clone::{clone_trait_object, DynClone};
+use dyn_clone::{clone_box, clone_trait_object, DynClone};
 use indexmap::IndexSet;
 
 use crate::{
-    lang::Letter,
+    dictionary::Dictionary,
+    lang::{Alphabet, AsciiCollation, Collation, Letter},
     rust::{DynEq, DynHash, DynOrd},
-    space::{Dimension, Distance, Location, Orientation},
+    space::{
+        self, Dimension, Distance, Line, Location, OccupancyBitmap, Orientation, Orientations,
+        Vector,
+    },
 };
 
 /// A board represents the playing area for a game. It consists of a set of [`Tile`] on which a
 /// [`Placement`] of [`Piece`] can be made. These tiles can also have other attributes that affect
 /// the score or gameplay when a piece is played on them.
-pub trait Board: Debug + DynClone {
+pub trait Board: Debug + DynClone + Send + Sync {
     /// Retrieve the sizing of this board.
     fn dimension(&self) -> Dimension;
 
@@ -44,7 +49,17 @@ pub trait Board: Debug + DynClone {
     fn calculate_points(&self, placement: &dyn Placement) -> i32;
 
     /// Commit the given [`Placement`] to this board.
-    fn place(&mut self, placement: dyn Placement) -> Result<i32, Error>;
+    ///
+    /// Committed pieces are locked (see [`LockedPiece`]) so that the letter chosen for a wildcard
+    /// cannot be reassigned once it is on the board.
+    fn place(&mut self, placement: Box<dyn Placement>) -> Result<i32, Error>;
+
+    /// Revert a [`Placement`] previously committed with [`Board::place`], restoring the [`Tile`]
+    /// at each of its locations (see [`Placement::locations`]) to an empty state.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidPlacement`] if the tiles do not currently hold
+    /// the given placement, in which case the board is unchanged.
+    fn remove_placement(&mut self, placement: &dyn Placement) -> Result<(), Error>;
 
     /// Retrieve the set of [`Tile`] that make up this board.
     fn tiles(&self) -> &dyn TileSet;
@@ -54,13 +69,647 @@ pub trait Board: Debug + DynClone {
 
     /// Retrieve the allowed [`Placement`] [`Orientation`] for the board.
     fn orientations(&self) -> &IndexSet<Box<dyn Orientation>>;
+
+    /// Count the occupied [`Tile`] on this board by the value of the [`Piece`] placed on them.
+    fn value_histogram(&self) -> BTreeMap<i32, usize> {
+        let mut histogram = BTreeMap::new();
+        for tile in self.tiles().occupied_tiles() {
+            if let Some(piece) = tile.piece() {
+                *histogram.entry(piece.value()).or_insert(0) += 1;
+            }
+        }
+
+        histogram
+    }
 }
 
 clone_trait_object!(Board);
 
+/// A [`Board`] of a fixed [`Dimension`] whose [`Tile`] are held in a [`TileSetImpl`].
+///
+/// A [`Placement`] is valid when it is along one of the board's orientations, every location is
+/// within the dimension and unoccupied, the first placement covers the start location (see
+/// [`first_move_covers_start`]), and later placements are connected to the pieces already on the
+/// board (see [`is_connected`]). Points are calculated by [`score_placement`].
+#[derive(Clone, Debug)]
+pub struct BoardImpl {
+    dimension: Dimension,
+    start: Location,
+    orientations: IndexSet<Box<dyn Orientation>>,
+    tiles: TileSetImpl,
+    placements: Vec<Box<dyn Placement>>,
+}
+
+impl BoardImpl {
+    /// Create an empty board.
+    ///
+    /// Returns an error if the occupancy of the board cannot be tracked for the given dimension
+    /// (see [`TileSetImpl::bounded`]).
+    pub fn new(
+        dimension: Dimension,
+        start: Location,
+        orientations: IndexSet<Box<dyn Orientation>>,
+    ) -> Result<BoardImpl, space::Error> {
+        Ok(BoardImpl {
+            dimension,
+            start,
+            orientations,
+            tiles: TileSetImpl::bounded(&dimension)?,
+            placements: Vec::new(),
+        })
+    }
+
+    /// Add the given [`TileAttribute`] to the [`Tile`] at the given [`Location`].
+    pub fn add_attribute(&mut self, location: &Location, attribute: Box<dyn TileAttribute>) {
+        self.tiles.add_attribute(location, attribute);
+    }
+
+    /// Retrieve the placements committed to this board, in the order they were placed.
+    pub fn placements(&self) -> &[Box<dyn Placement>] {
+        &self.placements
+    }
+}
+
+impl Board for BoardImpl {
+    fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    fn valid(&self, placement: &dyn Placement) -> bool {
+        self.orientations
+            .iter()
+            .any(|o| o.as_ref() == placement.orientation())
+            && placement
+                .locations()
+                .iter()
+                .all(|l| self.dimension.contains(l) && !self.tiles.is_occupied(l))
+            && first_move_covers_start(self, placement)
+            && is_connected(self, placement)
+    }
+
+    fn calculate_points(&self, placement: &dyn Placement) -> i32 {
+        score_placement(self, placement)
+    }
+
+    fn place(&mut self, placement: Box<dyn Placement>) -> Result<i32, Error> {
+        if !self.valid(placement.as_ref()) {
+            return Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: String::from("Placement is not valid on this board"),
+            });
+        }
+
+        let points = self.calculate_points(placement.as_ref());
+        for (location, piece) in placement.locations().iter().zip(placement.pieces()) {
+            self.tiles
+                .set_piece(location, Box::new(LockedPiece::new(piece.clone())));
+        }
+        self.placements.push(placement);
+
+        Ok(points)
+    }
+
+    fn remove_placement(&mut self, placement: &dyn Placement) -> Result<(), Error> {
+        let index = self
+            .placements
+            .iter()
+            .position(|p| p.as_ref() == placement)
+            .filter(|_| {
+                placement
+                    .locations()
+                    .iter()
+                    .zip(placement.pieces())
+                    .all(|(l, piece)| {
+                        self.tiles
+                            .piece(l)
+                            .is_some_and(|p| p.eq_exact(piece.as_ref()))
+                    })
+            })
+            .ok_or(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: String::from("Placement is not on this board"),
+            })?;
+
+        for location in placement.locations() {
+            self.tiles.remove_piece(&location);
+        }
+        self.placements.remove(index);
+
+        Ok(())
+    }
+
+    fn tiles(&self) -> &dyn TileSet {
+        &self.tiles
+    }
+
+    fn start(&self) -> &Location {
+        &self.start
+    }
+
+    fn orientations(&self) -> &IndexSet<Box<dyn Orientation>> {
+        &self.orientations
+    }
+}
+
+/// Calculate the score that the given [`Placement`] would receive on the given [`Board`]. This is
+/// a reference implementation of [`Board::calculate_points`].
+///
+/// The placement forms a main word along its orientation, extended by any pieces already on the
+/// board immediately before or after it. Each placed piece may also form a cross word along the
+/// board's other orientations with the pieces already adjacent to it; cross words of a single
+/// piece are not scored, and neither is a single-piece main word when it forms a cross word.
+///
+/// Each word is the sum of its piece values, where each value is first modified by every
+/// [`TileAttribute`] on the tiles of the placement and on any other unoccupied tile within the
+/// attribute's [`TileAttribute::radius`] of a placed piece. Attributes on tiles that were already
+/// occupied are not applied again. Each attribute is given the [`Distance`] from its tile to the
+/// piece and whether or not its tile is part of that word.
+pub fn score_placement(board: &dyn Board, placement: &dyn Placement) -> i32 {
+    let occupied: HashMap<Location, i32> = board
+        .tiles()
+        .occupied_tiles()
+        .iter()
+        .map(|tile| (*tile.location(), tile.base_value()))
+        .collect();
+    let placed: HashMap<Location, i32> = placement
+        .locations()
+        .into_iter()
+        .zip(placement.pieces().iter().map(|piece| piece.value()))
+        .collect();
+    let value_at = |location: &Location| placed.get(location).or(occupied.get(location)).copied();
+
+    let locations: HashSet<Location> = placed.keys().copied().collect();
+    let tile_attributes = board.tiles().attributes(&locations);
+    let mut attributes: Vec<(Location, &dyn TileAttribute)> = tile_attributes
+        .iter()
+        .filter(|(location, _)| !occupied.contains_key(location))
+        .flat_map(|(location, attributes)| attributes.iter().map(move |a| (*location, a.as_ref())))
+        .filter(|(location, attribute)| {
+            let radius = Distance::of((attribute.radius(), attribute.radius(), attribute.radius()));
+            placed.contains_key(location)
+                || placed
+                    .keys()
+                    .any(|p| Distance::between(location, p).is_within(&radius))
+        })
+        .collect();
+    attributes.sort_by_key(|(location, _)| *location);
+
+    let word_along = |start: &Location, orientation: &dyn Orientation| {
+        let mut first = *start;
+        while value_at(&orientation.go(&first, -1)).is_some() {
+            first = orientation.go(&first, -1);
+        }
+
+        let mut word = Vec::new();
+        let mut location = first;
+        while let Some(value) = value_at(&location) {
+            word.push((location, value));
+            location = orientation.go(&location, 1);
+        }
+
+        word
+    };
+
+    let score_word = |word: &[(Location, i32)]| -> i32 {
+        word.iter()
+            .map(|(location, value)| {
+                attributes
+                    .iter()
+                    .fold(*value, |value, (attribute_location, attribute)| {
+                        attribute.modify_value(
+                            value,
+                            &Distance::between(attribute_location, location),
+                            word.iter().any(|(l, _)| l == attribute_location),
+                        )
+                    })
+            })
+            .sum()
+    };
+
+    let cross_words: Vec<Vec<(Location, i32)>> = placement
+        .locations()
+        .iter()
+        .flat_map(|location| {
+            board
+                .orientations()
+                .iter()
+                .filter(|orientation| orientation.as_ref() != placement.orientation())
+                .map(move |orientation| word_along(location, orientation.as_ref()))
+        })
+        .filter(|word| word.len() > 1)
+        .collect();
+
+    let main_word = match placement.locations().first() {
+        Some(start) => word_along(start, placement.orientation()),
+        None => Vec::new(),
+    };
+    let main_score = if main_word.len() > 1 || cross_words.is_empty() {
+        score_word(&main_word)
+    } else {
+        0
+    };
+
+    main_score + cross_words.iter().map(|word| score_word(word)).sum::<i32>()
+}
+
+/// Calculate the score that the given [`Placement`] would receive on the given [`Board`] as with
+/// [`score_placement`], adding the given bonus when the placement uses all of the pieces from a
+/// rack of the given size. A bonus of 0 disables it.
+pub fn score_placement_with_bonus(
+    board: &dyn Board,
+    placement: &dyn Placement,
+    rack_size: usize,
+    bonus: i32,
+) -> i32 {
+    let score = score_placement(board, placement);
+    if placement.pieces().len() == rack_size {
+        score + bonus
+    } else {
+        score
+    }
+}
+
+/// Draw the given [`Board`] as text for debugging. Each row of the board is written on its own
+/// line, with the character of the [`Letter`] on each occupied [`Tile`] and `.` for empty tiles.
+/// A [`Piece`] without a letter is drawn as [`WORD_PLACEHOLDER`]. Boards with more than one layer
+/// of depth are drawn one layer at a time, separated by a blank line.
+pub fn render(board: &dyn Board) -> String {
+    let dimension = board.dimension();
+    let letters = occupied_letters(board);
+
+    (0..dimension.depth() as i32)
+        .map(|z| {
+            (0..dimension.height() as i32)
+                .map(|y| {
+                    (0..dimension.width() as i32)
+                        .map(|x| {
+                            letters
+                                .get(&Location::at((x, y, z)))
+                                .copied()
+                                .unwrap_or('.')
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Find every [`Placement`] that could be made on the given [`Board`] with pieces from the given
+/// rack such that the main word it forms, as described by [`score_placement`], is at least two
+/// letters long and in the given [`Dictionary`].
+///
+/// Each placement covers consecutive empty tiles along one of the board's orientations, and at
+/// least one of them must be an anchor: an empty tile next to an occupied one or, on an empty
+/// board, the start location. Only starts within the length of the rack before an anchor are
+/// searched. Placements that the board does not consider [`Board::valid`] are discarded. A
+/// wildcard without a [`Letter`] is tried as each letter of the given [`Alphabet`].
+///
+/// A piece laid next to pieces on the board along another orientation forms a cross word, so only
+/// the letters allowed by [`cross_checks`] are tried there.
+///
+/// Playing through pieces already on the board is not supported: since a [`Placement`] lays its
+/// pieces on consecutive tiles, a word can only extend the pieces on the board at its ends. For
+/// example, with "AT" on the board, "CATS" cannot be formed by placing "C" and "S" on either side.
+pub fn generate_moves(
+    board: &dyn Board,
+    rack: &[Box<dyn Piece>],
+    dictionary: &dyn Dictionary,
+    alphabet: &dyn Alphabet,
+) -> Vec<Box<dyn Placement>> {
+    let mut search = MoveSearch {
+        board,
+        dictionary,
+        alphabet,
+        letters: occupied_letters(board),
+        anchors: anchors(board),
+        cross_checks: HashMap::new(),
+        moves: Vec::new(),
+    };
+
+    let dimension = board.dimension();
+    let mut rack = rack.to_vec();
+    for orientation in board.orientations() {
+        search.cross_checks = cross_checks(board, dictionary, alphabet, orientation.as_ref());
+        let mut starts = BTreeSet::new();
+        for anchor in &search.anchors {
+            for back in 0..rack.len() as i32 {
+                let start = orientation.go(anchor, -back);
+                if !dimension.contains(&start) || search.letters.contains_key(&start) {
+                    break;
+                }
+                starts.insert(start);
+            }
+        }
+
+        for start in starts {
+            let mut word = String::new();
+            let mut location = orientation.go(&start, -1);
+            while let Some(character) = search.letters.get(&location) {
+                word.insert(0, *character);
+                location = orientation.go(&location, -1);
+            }
+
+            search.extend(
+                &start,
+                orientation.as_ref(),
+                &mut word,
+                &mut Vec::new(),
+                &mut rack,
+                false,
+            );
+        }
+    }
+
+    search.moves
+}
+
+/// Find the anchors of the given [`Board`]: the locations from which a [`Placement`] can connect
+/// to the pieces already on the board. These are the empty tiles within the board's dimension
+/// that are next to an occupied tile along one of the board's orientations or, on an empty board,
+/// just the start location.
+pub fn anchors(board: &dyn Board) -> HashSet<Location> {
+    let occupied = board.tiles().occupied_tiles();
+    if occupied.is_empty() {
+        return HashSet::from([*board.start()]);
+    }
+
+    let dimension = board.dimension();
+    let orientations: Vec<Box<dyn Orientation>> = board.orientations().iter().cloned().collect();
+    let locations: HashSet<Location> = occupied.iter().map(|tile| *tile.location()).collect();
+    locations
+        .iter()
+        .flat_map(|location| location.neighbors(&orientations))
+        .filter(|location| dimension.contains(location) && !locations.contains(location))
+        .collect()
+}
+
+/// Find, for each of the anchors of the given [`Board`] (see [`anchors`]), the characters of the
+/// letters of the given [`Alphabet`] that could be placed there by a [`Placement`] along the given
+/// orientation without forming an invalid cross word. A cross word is formed along each of the
+/// board's other orientations with the pieces adjacent to the anchor and must be in the given
+/// [`Dictionary`]. Every letter is allowed at an anchor that would form no cross words.
+pub fn cross_checks(
+    board: &dyn Board,
+    dictionary: &dyn Dictionary,
+    alphabet: &dyn Alphabet,
+    orientation: &dyn Orientation,
+) -> HashMap<Location, HashSet<char>> {
+    let candidates: Vec<char> = alphabet.letters().iter().map(|l| l.character()).collect();
+    let letters = occupied_letters(board);
+    let word_along = |location: &Location, orientation: &dyn Orientation, direction: i32| {
+        let mut word = String::new();
+        let mut next = orientation.go(location, direction);
+        while let Some(character) = letters.get(&next) {
+            word.push(*character);
+            next = orientation.go(&next, direction);
+        }
+
+        word
+    };
+
+    anchors(board)
+        .into_iter()
+        .map(|anchor| {
+            let cross_words: Vec<(String, String)> = board
+                .orientations()
+                .iter()
+                .filter(|o| o.as_ref() != orientation)
+                .map(|o| {
+                    let before: String =
+                        word_along(&anchor, o.as_ref(), -1).chars().rev().collect();
+                    (before, word_along(&anchor, o.as_ref(), 1))
+                })
+                .filter(|(before, after)| !before.is_empty() || !after.is_empty())
+                .collect();
+            let allowed = candidates
+                .iter()
+                .copied()
+                .filter(|character| {
+                    cross_words.iter().all(|(before, after)| {
+                        dictionary.contains(&format!("{}{}{}", before, character, after))
+                    })
+                })
+                .collect();
+
+            (anchor, allowed)
+        })
+        .collect()
+}
+
+/// Determine whether or not the given [`Placement`] satisfies the rule that the first placement
+/// on a [`Board`] must cover its start location. This is always true once the board has an
+/// occupied [`Tile`], so a board can apply it from [`Board::valid`] for every placement.
+pub fn first_move_covers_start(board: &dyn Board, placement: &dyn Placement) -> bool {
+    !board.tiles().occupied_tiles().is_empty() || placement.locations().contains(board.start())
+}
+
+/// Determine whether or not the given [`Placement`] connects to the pieces already on the given
+/// [`Board`], meaning that at least one of its locations is occupied or is next to an occupied
+/// [`Tile`] along one of the board's orientations. Any placement is connected on an empty board;
+/// see [`first_move_covers_start`] for the rule that applies there instead.
+pub fn is_connected(board: &dyn Board, placement: &dyn Placement) -> bool {
+    let occupied: HashSet<Location> = board
+        .tiles()
+        .occupied_tiles()
+        .iter()
+        .map(|tile| *tile.location())
+        .collect();
+    if occupied.is_empty() {
+        return true;
+    }
+
+    let orientations: Vec<Box<dyn Orientation>> = board.orientations().iter().cloned().collect();
+    placement.locations().iter().any(|location| {
+        occupied.contains(location)
+            || location
+                .neighbors(&orientations)
+                .iter()
+                .any(|neighbor| occupied.contains(neighbor))
+    })
+}
+
+/// Confirm that the given [`Placement`] only overlaps occupied tiles on the given [`Board`] where
+/// the [`Piece`] already there has the same [`Letter`] character as the piece placed on it, as when
+/// a word reuses a letter of a word it crosses. Characters are compared ignoring case, as a
+/// [`Dictionary`] compares words.
+///
+/// Returns an error of kind [`ErrorKind::InvalidPlacement`] for the first conflicting location.
+pub fn validate_overlap(board: &dyn Board, placement: &dyn Placement) -> Result<(), Error> {
+    let character = |piece: &dyn Piece| {
+        piece
+            .letter()
+            .as_ref()
+            .map(|l| l.character().to_lowercase().collect::<String>())
+    };
+    let occupied: HashMap<Location, Option<String>> = board
+        .tiles()
+        .occupied_tiles()
+        .iter()
+        .filter_map(|tile| {
+            tile.piece()
+                .map(|piece| (*tile.location(), character(piece)))
+        })
+        .collect();
+
+    for (location, piece) in placement.locations().iter().zip(placement.pieces()) {
+        if occupied
+            .get(location)
+            .is_some_and(|existing| *existing != character(piece.as_ref()))
+        {
+            return Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: format!("Placement conflicts with the piece at {:?}", location),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Map each occupied [`Tile`] on the given [`Board`] to the character of its [`Letter`], or
+/// [`WORD_PLACEHOLDER`] if its [`Piece`] has none.
+fn occupied_letters(board: &dyn Board) -> HashMap<Location, char> {
+    board
+        .tiles()
+        .occupied_tiles()
+        .iter()
+        .filter_map(|tile| {
+            tile.piece().map(|piece| {
+                (
+                    *tile.location(),
+                    piece
+                        .letter()
+                        .as_ref()
+                        .map_or(WORD_PLACEHOLDER, |letter| letter.character()),
+                )
+            })
+        })
+        .collect()
+}
+
+/// The state of a depth-first search for the moves found by [`generate_moves`].
+struct MoveSearch<'a> {
+    board: &'a dyn Board,
+    dictionary: &'a dyn Dictionary,
+    alphabet: &'a dyn Alphabet,
+    letters: HashMap<Location, char>,
+    anchors: HashSet<Location>,
+    cross_checks: HashMap<Location, HashSet<char>>,
+    moves: Vec<Box<dyn Placement>>,
+}
+
+impl MoveSearch<'_> {
+    /// Record the given pieces as a move if they form a word, then try laying each remaining
+    /// piece from the rack on the next tile. The word holds the letters from any pieces on the
+    /// board before the start location followed by those of the given pieces.
+    fn extend(
+        &mut self,
+        start: &Location,
+        orientation: &dyn Orientation,
+        word: &mut String,
+        pieces: &mut Vec<Box<dyn Piece>>,
+        rack: &mut Vec<Box<dyn Piece>>,
+        anchored: bool,
+    ) {
+        let next = orientation.go(start, pieces.len() as i32);
+        if anchored {
+            self.record(start, orientation, word, pieces, &next);
+        }
+
+        if !self.board.dimension().contains(&next) || self.letters.contains_key(&next) {
+            return;
+        }
+
+        let anchored = anchored || self.anchors.contains(&next);
+        let mut tried: Vec<(bool, char, i32)> = Vec::new();
+        for i in 0..rack.len() {
+            let piece = rack.remove(i);
+            for choice in resolve_wildcard(piece.as_ref(), self.alphabet) {
+                let character = choice
+                    .letter()
+                    .as_ref()
+                    .map_or(WORD_PLACEHOLDER, |letter| letter.character());
+                let key = (choice.wild(), character, choice.value());
+                if tried.contains(&key) || !self.allowed(&next, character) {
+                    continue;
+                }
+                tried.push(key);
+
+                word.push(character);
+                if self.dictionary.is_prefix(word) {
+                    pieces.push(choice);
+                    self.extend(start, orientation, word, pieces, rack, anchored);
+                    pieces.pop();
+                }
+                word.pop();
+            }
+            rack.insert(i, piece);
+        }
+    }
+
+    /// Determine whether or not the given character passes the cross checks at the given
+    /// location, ignoring case.
+    fn allowed(&self, location: &Location, character: char) -> bool {
+        self.cross_checks.get(location).is_none_or(|allowed| {
+            allowed
+                .iter()
+                .any(|c| c.to_lowercase().eq(character.to_lowercase()))
+        })
+    }
+
+    /// Record a move for the given pieces if the main word they form, including any pieces on the
+    /// board from the given location onward, is in the dictionary.
+    fn record(
+        &mut self,
+        start: &Location,
+        orientation: &dyn Orientation,
+        word: &str,
+        pieces: &[Box<dyn Piece>],
+        next: &Location,
+    ) {
+        let mut word = word.to_string();
+        let mut location = *next;
+        while let Some(character) = self.letters.get(&location) {
+            word.push(*character);
+            location = orientation.go(&location, 1);
+        }
+
+        if word.chars().count() < 2 || !self.dictionary.contains(&word) {
+            return;
+        }
+
+        let placement = PlacementImpl::new(*start, clone_box(orientation), pieces.to_vec());
+        if self.board.valid(&placement) {
+            self.moves.push(Box::new(placement));
+        }
+    }
+}
+
+/// Retrieve the pieces that could be played for the given [`Piece`]: a copy of the piece assigned
+/// each letter of the given [`Alphabet`] if it is a wildcard without a [`Letter`], otherwise the
+/// piece itself.
+fn resolve_wildcard(piece: &dyn Piece, alphabet: &dyn Alphabet) -> Vec<Box<dyn Piece>> {
+    if !piece.wild() || piece.letter().is_some() {
+        return vec![clone_box(piece)];
+    }
+
+    alphabet
+        .letters()
+        .into_iter()
+        .map(|letter| {
+            let mut resolved = clone_box(piece);
+            resolved.set_letter(Some(letter));
+            resolved
+        })
+        .collect()
+}
+
 /// A piece represents a game token that contains a [`Letter`] and has attributes such as a value
 /// and a wildcard status.
-pub trait Piece: Debug + DynClone + DynEq + DynHash {
+pub trait Piece: Debug + DynClone + DynEq + DynHash + Send + Sync {
     /// Set the [`Letter`] that this piece represents.
     fn set_letter(&mut self, letter: Option<Box<dyn Letter>>);
 
@@ -73,18 +722,54 @@ pub trait Piece: Debug + DynClone + DynEq + DynHash {
     /// Determine whether or not this piece represents a wildcard (no specific [`Letter`] until one
     /// is chosen).
     fn wild(&self) -> bool;
+
+    /// Determine whether or not this piece is identical to the given piece. Unlike `==`, which
+    /// treats all wildcards as interchangeable, this also distinguishes wildcards by the
+    /// [`Letter`] they have been resolved to. Values are not compared.
+    fn eq_exact(&self, other: &dyn Piece) -> bool {
+        self.wild() == other.wild() && self.letter() == other.letter()
+    }
+
+    /// Compare this piece to the given piece for display, such as when sorting a rack with
+    /// `sort_by`: pieces with a [`Letter`] come before wildcards and are ordered by the character
+    /// of that letter, with pieces lacking a letter first. The letters of wildcards are not
+    /// compared. Pieces that are otherwise the same are ordered by value.
+    ///
+    /// This ordering is finer than `==`, which ignores values, so it is not used for equality.
+    fn display_cmp(&self, other: &dyn Piece) -> Ordering {
+        let character = |letter: &Option<Box<dyn Letter>>| letter.as_ref().map(|l| l.character());
+        self.wild()
+            .cmp(&other.wild())
+            .then_with(|| {
+                if self.wild() {
+                    Ordering::Equal
+                } else {
+                    character(self.letter()).cmp(&character(other.letter()))
+                }
+            })
+            .then_with(|| self.value().cmp(&other.value()))
+    }
 }
 
 clone_trait_object!(Piece);
 
 impl Eq for dyn Piece {}
 
+/// Hashing agrees with equality: only the wildcard status and, for other pieces, the [`Letter`]
+/// contribute to the hash.
 impl Hash for dyn Piece {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.dyn_hash(state)
+        self.wild().hash(state);
+        if !self.wild() {
+            self.letter().hash(state);
+        }
     }
 }
 
+/// Pieces are equal when they would be interchangeable for scoring: all wildcards are equal
+/// regardless of any [`Letter`] they have been resolved to, and other pieces are equal when their
+/// letters match. Values are not compared. See [`Piece::eq_exact`] to distinguish resolved
+/// wildcards.
 impl PartialEq<dyn Piece> for dyn Piece {
     fn eq(&self, other: &dyn Piece) -> bool {
         if self.wild() != other.wild() {
@@ -107,12 +792,161 @@ impl PartialEq<dyn Piece> for dyn Piece {
             return false;
         }
 
-        return true;
+        true
+    }
+}
+
+/// A locked piece wraps another [`Piece`] and ignores any attempt to change its [`Letter`],
+/// preventing accidental reassignment of a resolved wildcard after it has been committed.
+#[derive(Clone, Debug, Eq)]
+pub struct LockedPiece {
+    piece: Box<dyn Piece>,
+}
+
+impl LockedPiece {
+    pub fn new(piece: Box<dyn Piece>) -> LockedPiece {
+        LockedPiece { piece }
+    }
+}
+
+impl Hash for LockedPiece {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.piece.hash(state)
+    }
+}
+
+impl PartialEq for LockedPiece {
+    fn eq(&self, other: &Self) -> bool {
+        self.piece.eq(&other.piece)
+    }
+}
+
+impl Piece for LockedPiece {
+    /// Locked pieces keep their current letter; this is a no-op.
+    fn set_letter(&mut self, _letter: Option<Box<dyn Letter>>) {}
+
+    fn letter(&self) -> &Option<Box<dyn Letter>> {
+        self.piece.letter()
+    }
+
+    fn value(&self) -> i32 {
+        self.piece.value()
+    }
+
+    fn wild(&self) -> bool {
+        self.piece.wild()
+    }
+}
+
+/// A rack holds the [`Piece`] available to a player for making a [`Placement`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rack {
+    pieces: Vec<Box<dyn Piece>>,
+}
+
+impl Rack {
+    pub fn new() -> Rack {
+        Rack::default()
+    }
+
+    /// Retrieve the pieces held in this rack, in the order they were added.
+    pub fn pieces(&self) -> &Vec<Box<dyn Piece>> {
+        &self.pieces
+    }
+
+    /// Add the given piece to this rack.
+    pub fn add(&mut self, piece: Box<dyn Piece>) {
+        self.pieces.push(piece);
+    }
+
+    /// Remove a piece equal to the given piece from this rack, returning it if one was found. All
+    /// wildcards are equal, so any wildcard will satisfy a request to remove one.
+    pub fn remove(&mut self, piece: &dyn Piece) -> Option<Box<dyn Piece>> {
+        self.pieces
+            .iter()
+            .position(|p| p.as_ref() == piece)
+            .map(|i| self.pieces.remove(i))
+    }
+
+    /// Determine whether or not the pieces in this rack can spell the given word. Each character
+    /// is matched, ignoring case, to an unused piece whose [`Letter`] has that character, and any
+    /// character left unmatched consumes an unused wildcard.
+    pub fn can_spell(&self, word: &[char]) -> bool {
+        let mut letters: HashMap<String, usize> = HashMap::new();
+        for piece in self.pieces.iter().filter(|p| !p.wild()) {
+            if let Some(letter) = piece.letter() {
+                *letters
+                    .entry(letter.character().to_lowercase().collect())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        let mut wildcards = self.pieces.iter().filter(|p| p.wild()).count();
+        for character in word {
+            match letters.get_mut(&character.to_lowercase().collect::<String>()) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ if wildcards > 0 => wildcards -= 1,
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Sort the pieces in this rack for display: pieces with a [`Letter`] come before wildcards
+    /// and are ordered by the given [`Collation`], or by [`AsciiCollation`] when none is given,
+    /// with pieces lacking a letter first. Pieces that are otherwise the same are ordered by value.
+    pub fn sort(&mut self, collation: Option<&dyn Collation>) {
+        let collation = collation.unwrap_or(&AsciiCollation);
+        self.pieces.sort_by(|a, b| {
+            a.wild()
+                .cmp(&b.wild())
+                .then_with(|| match (a.wild(), a.letter(), b.letter()) {
+                    (false, Some(x), Some(y)) => collation.order(x.as_ref(), y.as_ref()),
+                    (false, x, y) => x.is_some().cmp(&y.is_some()),
+                    (true, _, _) => Ordering::Equal,
+                })
+                .then_with(|| a.value().cmp(&b.value()))
+        });
+    }
+
+    /// Retrieve the number of pieces held in this rack.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Determine whether or not this rack holds no pieces.
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    /// Determine whether or not this rack holds at least the given number of pieces.
+    pub fn is_full(&self, capacity: usize) -> bool {
+        self.pieces.len() >= capacity
+    }
+
+    /// Add pieces taken from the given source, such as pieces drawn from a bag, until this rack
+    /// holds the given number of pieces or the source is exhausted. Returns the number of pieces
+    /// added.
+    pub fn refill<I: IntoIterator<Item = Box<dyn Piece>>>(
+        &mut self,
+        capacity: usize,
+        source: I,
+    ) -> usize {
+        let before = self.pieces.len();
+        self.pieces
+            .extend(source.into_iter().take(capacity.saturating_sub(before)));
+
+        self.pieces.len() - before
     }
 }
 
+/// The character used by [`Placement::word`] for a [`Piece`] without a [`Letter`], such as a
+/// wildcard that has not been assigned one.
+pub const WORD_PLACEHOLDER: char = '?';
+
 /// A placement is a specific grouping of pieces with a location and orientation.
-pub trait Placement: Debug + DynClone + DynEq + DynHash {
+pub trait Placement: Debug + DynClone + DynEq + DynHash + Send + Sync {
     /// Retrieve the starting location of this placement.
     fn start_location(&self) -> &Location;
 
@@ -121,6 +955,35 @@ pub trait Placement: Debug + DynClone + DynEq + DynHash {
 
     /// Retrieve the pieces contained within this placement.
     fn pieces(&self) -> &Vec<Box<dyn Piece>>;
+
+    /// Retrieve the word spelled by the pieces in this placement, in order. Any piece without a
+    /// [`Letter`] is represented by [`WORD_PLACEHOLDER`].
+    fn word(&self) -> String {
+        self.pieces()
+            .iter()
+            .map(|piece| {
+                piece
+                    .letter()
+                    .as_ref()
+                    .map_or(WORD_PLACEHOLDER, |letter| letter.character())
+            })
+            .collect()
+    }
+
+    /// Retrieve the location of each piece in this placement, in order, by stepping along the
+    /// orientation from the start location.
+    fn locations(&self) -> Vec<Location> {
+        (0..self.pieces().len())
+            .map(|i| self.orientation().go(self.start_location(), i as i32))
+            .collect()
+    }
+
+    /// Retrieve the location of the last piece in this placement. This is the start location if
+    /// the placement has no more than one piece.
+    fn end_location(&self) -> Location {
+        let steps = self.pieces().len().saturating_sub(1) as i32;
+        self.orientation().go(self.start_location(), steps)
+    }
 }
 
 clone_trait_object!(Placement);
@@ -140,12 +1003,12 @@ impl PartialEq<dyn Placement> for dyn Placement {
 }
 
 /// A tile represents a location on the game [`Board`] that can be occupied by a [`Piece`].
-pub trait Tile: Debug + DynClone + DynEq + DynOrd + DynHash {
+pub trait Tile: Debug + DynClone + DynEq + DynOrd + DynHash + Send + Sync {
     /// Retrieve this tile's location.
     fn location(&self) -> &Location;
 
     /// Set the [`Piece`] that occupies this tile.
-    fn set_piece(&mut self, piece: dyn Piece);
+    fn set_piece(&mut self, piece: Box<dyn Piece>);
 
     /// Get the piece that occupies this tile or nothing if empty.
     fn piece(&self) -> Option<&dyn Piece>;
@@ -155,7 +1018,7 @@ pub trait Tile: Debug + DynClone + DynEq + DynOrd + DynHash {
     fn base_value(&self) -> i32;
 
     /// Add the given attribute to this tile that may affect the score or gameplay.
-    fn add_attribute(&mut self, attribute: dyn TileAttribute);
+    fn add_attribute(&mut self, attribute: Box<dyn TileAttribute>);
 
     /// Remove the given attribute from this tile that may affect the score or gameplay.
     fn remove_attribute(&mut self, attribute: &dyn TileAttribute);
@@ -188,7 +1051,7 @@ impl PartialOrd<dyn Tile> for dyn Tile {
 
 /// A tile attribute represents a modifier that is applied to the value of a [`Piece`] placed on a
 /// [`Tile`] or nearby tiles to increase or decrease the final point score or affect gameplay.
-pub trait TileAttribute: Debug + DynClone {
+pub trait TileAttribute: Debug + DynClone + DynEq + DynHash + Send + Sync {
     /// Modify the given value based on the rules of this attribute.
     ///
     /// The [`Distance`] is from the [`Tile`] to which this attribute belongs to where the given
@@ -202,12 +1065,125 @@ pub trait TileAttribute: Debug + DynClone {
     /// Determine whether or not this attribute should be visible to the player before a piece is
     /// placed on the tile.
     fn visible(&self) -> bool;
+
+    /// Retrieve the largest [`Distance`] (in every direction) from its [`Tile`] at which this
+    /// attribute can modify the value of a [`Piece`] on another tile. The default of 0 means that
+    /// only pieces placed on the tile itself, or words passing through it, are affected.
+    fn radius(&self) -> i32 {
+        0
+    }
 }
 
 clone_trait_object!(TileAttribute);
 
+impl Eq for dyn TileAttribute {}
+
+impl Hash for dyn TileAttribute {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dyn_hash(state)
+    }
+}
+
+impl PartialEq<dyn TileAttribute> for dyn TileAttribute {
+    fn eq(&self, other: &dyn TileAttribute) -> bool {
+        self.as_dyn_eq() == other.as_dyn_eq()
+    }
+}
+
+/// A radius bonus is a [`TileAttribute`] with an area effect: it multiplies the value of any
+/// [`Piece`] within `radius` tiles (in every direction) of the [`Tile`] to which it belongs,
+/// including that tile itself.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RadiusBonus {
+    radius: i32,
+    factor: i32,
+}
+
+impl RadiusBonus {
+    pub fn new(radius: i32, factor: i32) -> RadiusBonus {
+        RadiusBonus { radius, factor }
+    }
+}
+
+impl TileAttribute for RadiusBonus {
+    fn modify_value(&self, value: i32, distance: &Distance, _same_word: bool) -> i32 {
+        if distance.is_within(&Distance::of((self.radius, self.radius, self.radius))) {
+            value * self.factor
+        } else {
+            value
+        }
+    }
+
+    fn visible(&self) -> bool {
+        true
+    }
+
+    fn radius(&self) -> i32 {
+        self.radius
+    }
+}
+
+/// A letter multiplier is a [`TileAttribute`] that multiplies the value of a [`Piece`] placed
+/// directly on the [`Tile`] to which it belongs. Pieces on any other tile are unaffected.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LetterMultiplier {
+    factor: i32,
+}
+
+impl LetterMultiplier {
+    pub fn new(factor: i32) -> LetterMultiplier {
+        LetterMultiplier { factor }
+    }
+}
+
+impl TileAttribute for LetterMultiplier {
+    fn modify_value(&self, value: i32, distance: &Distance, _same_word: bool) -> i32 {
+        if distance == Distance::zero() {
+            value * self.factor
+        } else {
+            value
+        }
+    }
+
+    fn visible(&self) -> bool {
+        true
+    }
+}
+
+/// A word multiplier is a [`TileAttribute`] that multiplies the value of every [`Piece`] in the
+/// same word as the [`Tile`] to which it belongs, no matter how far away. Pieces in other words,
+/// such as a cross word, are unaffected.
+///
+/// Applying this to each piece of a word is equivalent to multiplying the word's total, and since
+/// multiplication is commutative the result is the same as the conventional order of applying any
+/// [`LetterMultiplier`] to individual pieces first and word multipliers to the total afterward.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WordMultiplier {
+    factor: i32,
+}
+
+impl WordMultiplier {
+    pub fn new(factor: i32) -> WordMultiplier {
+        WordMultiplier { factor }
+    }
+}
+
+impl TileAttribute for WordMultiplier {
+    fn modify_value(&self, value: i32, _distance: &Distance, same_word: bool) -> i32 {
+        if same_word {
+            value * self.factor
+        } else {
+            value
+        }
+    }
+
+    fn visible(&self) -> bool {
+        true
+    }
+}
+
 /// A tile set is a collection of [`Tile`] belonging to a [`Board`].
-pub trait TileSet: Debug + DynClone {
+pub trait TileSet: Debug + DynClone + Send + Sync {
     /// Remove all [`Tile`] from this set.
     fn clear(&mut self);
 
@@ -215,10 +1191,21 @@ pub trait TileSet: Debug + DynClone {
     /// created.
     fn tile(&mut self, location: &Location) -> &dyn Tile;
 
+    /// Retrieve the [`Tile`] at each [`Location`] of the given [`Line`], in the order the line is
+    /// traversed. As with [`TileSet::tile`], any tile that does not exist will be created.
+    fn tiles_in_line(&mut self, line: &Line) -> Vec<&dyn Tile>;
+
+    /// Discard any [`Tile`] that has neither a [`Piece`] nor a [`TileAttribute`], such as those
+    /// created on demand by [`TileSet::tile`]. The set's contents are otherwise unchanged.
+    fn compact(&mut self);
+
     /// Retrieve the subset of [`Tile`] which are occupied by a [`Piece`].
     fn occupied_tiles(&self) -> &HashSet<Box<dyn Tile>>;
 
-    /// Retrieve all [`TileAttribute`] for the given set of [`Location`].
+    /// Retrieve the [`TileAttribute`] of this set by [`Location`]. The map must include the
+    /// attributes at each of the given locations and may include others. [`score_placement`] asks
+    /// only for the locations of a placement and relies on the map also including any attribute
+    /// whose [`TileAttribute::radius`] reaches one of them.
     fn attributes(
         &self,
         locations: &HashSet<Location>,
@@ -227,16 +1214,246 @@ pub trait TileSet: Debug + DynClone {
 
 clone_trait_object!(TileSet);
 
+/// A [`Tile`] that holds at most one [`Piece`] and any number of [`TileAttribute`]. Tiles are
+/// equal, ordered, and hashed by location alone.
+#[derive(Clone, Debug)]
+pub struct TileImpl {
+    location: Location,
+    piece: Option<Box<dyn Piece>>,
+    attributes: HashSet<Box<dyn TileAttribute>>,
+}
+
+impl TileImpl {
+    pub fn new(location: Location) -> TileImpl {
+        TileImpl {
+            location,
+            piece: Option::None,
+            attributes: HashSet::new(),
+        }
+    }
+
+    /// Remove the [`Piece`] that occupies this tile, returning it if there was one.
+    pub fn remove_piece(&mut self) -> Option<Box<dyn Piece>> {
+        self.piece.take()
+    }
+}
+
+impl Tile for TileImpl {
+    fn location(&self) -> &Location {
+        &self.location
+    }
+
+    fn set_piece(&mut self, piece: Box<dyn Piece>) {
+        self.piece = Option::Some(piece);
+    }
+
+    fn piece(&self) -> Option<&dyn Piece> {
+        self.piece.as_deref()
+    }
+
+    fn base_value(&self) -> i32 {
+        self.piece.as_ref().map_or(0, |p| p.value())
+    }
+
+    fn add_attribute(&mut self, attribute: Box<dyn TileAttribute>) {
+        self.attributes.insert(attribute);
+    }
+
+    fn remove_attribute(&mut self, attribute: &dyn TileAttribute) {
+        self.attributes.retain(|a| a.as_ref() != attribute);
+    }
+
+    fn attributes(&self) -> &HashSet<Box<dyn TileAttribute>> {
+        &self.attributes
+    }
+}
+
+impl Eq for TileImpl {}
+
+impl PartialEq for TileImpl {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+    }
+}
+
+impl Ord for TileImpl {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.location.cmp(&other.location)
+    }
+}
+
+impl PartialOrd for TileImpl {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for TileImpl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.location.hash(state)
+    }
+}
+
+/// A [`TileSet`] of [`TileImpl`] stored by location. Tiles are created on demand. A tile set
+/// created with [`TileSetImpl::bounded`] also tracks occupancy in an [`OccupancyBitmap`] for fast
+/// queries with [`TileSetImpl::is_occupied`].
+#[derive(Clone, Debug, Default)]
+pub struct TileSetImpl {
+    tiles: HashMap<Location, TileImpl>,
+    occupied: HashSet<Box<dyn Tile>>,
+    attributes: HashMap<Location, Vec<Box<dyn TileAttribute>>>,
+    occupancy: Option<OccupancyBitmap>,
+}
+
+impl TileSetImpl {
+    /// Create an empty tile set that is not confined to any dimension.
+    pub fn new() -> TileSetImpl {
+        TileSetImpl::default()
+    }
+
+    /// Create an empty tile set for a board of the given [`Dimension`] that tracks occupancy in
+    /// an [`OccupancyBitmap`]. Pieces may still be set outside the dimension, but they are then
+    /// found by [`TileSetImpl::is_occupied`] only through the slower lookup by location.
+    ///
+    /// Returns an error if the bitmap cannot be created for the dimension.
+    pub fn bounded(dimension: &Dimension) -> Result<TileSetImpl, space::Error> {
+        Ok(TileSetImpl {
+            occupancy: Some(OccupancyBitmap::new(dimension)?),
+            ..TileSetImpl::default()
+        })
+    }
+
+    /// Determine whether or not the [`Tile`] at the given [`Location`] holds a [`Piece`].
+    pub fn is_occupied(&self, location: &Location) -> bool {
+        match &self.occupancy {
+            Some(bitmap) if bitmap.dimension().contains(location) => bitmap.get(location),
+            _ => self.piece(location).is_some(),
+        }
+    }
+
+    /// Retrieve the number of [`Tile`] in this set, including empty tiles created on demand.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// Determine whether or not this set has no [`Tile`].
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Retrieve the [`Piece`] at the given [`Location`] without creating a [`Tile`] there.
+    pub fn piece(&self, location: &Location) -> Option<&dyn Piece> {
+        self.tiles.get(location).and_then(|t| t.piece())
+    }
+
+    /// Set the [`Piece`] that occupies the [`Tile`] at the given [`Location`].
+    pub fn set_piece(&mut self, location: &Location, piece: Box<dyn Piece>) {
+        let tile = self.tile_mut(location);
+        tile.set_piece(piece);
+        let tile = Box::new(tile.clone());
+        self.occupied.replace(tile);
+        if let Some(bitmap) = &mut self.occupancy {
+            bitmap.set(location);
+        }
+    }
+
+    /// Remove the [`Piece`] from the [`Tile`] at the given [`Location`], returning it if there
+    /// was one.
+    pub fn remove_piece(&mut self, location: &Location) -> Option<Box<dyn Piece>> {
+        let tile = self.tiles.get_mut(location)?;
+        let piece = tile.remove_piece()?;
+        let tile: Box<dyn Tile> = Box::new(tile.clone());
+        self.occupied.remove(&tile);
+        if let Some(bitmap) = &mut self.occupancy {
+            bitmap.clear(location);
+        }
+        Some(piece)
+    }
+
+    /// Add the given [`TileAttribute`] to the [`Tile`] at the given [`Location`].
+    pub fn add_attribute(&mut self, location: &Location, attribute: Box<dyn TileAttribute>) {
+        let tile = self.tile_mut(location);
+        tile.add_attribute(attribute.clone());
+        if tile.piece.is_some() {
+            let tile = Box::new(tile.clone());
+            self.occupied.replace(tile);
+        }
+        self.attributes
+            .entry(*location)
+            .or_default()
+            .push(attribute);
+    }
+
+    fn tile_mut(&mut self, location: &Location) -> &mut TileImpl {
+        self.tiles
+            .entry(*location)
+            .or_insert_with(|| TileImpl::new(*location))
+    }
+}
+
+impl TileSet for TileSetImpl {
+    fn clear(&mut self) {
+        if let Some(bitmap) = &mut self.occupancy {
+            for tile in &self.occupied {
+                bitmap.clear(tile.location());
+            }
+        }
+        self.tiles.clear();
+        self.occupied.clear();
+        self.attributes.clear();
+    }
+
+    fn tile(&mut self, location: &Location) -> &dyn Tile {
+        self.tile_mut(location)
+    }
+
+    fn tiles_in_line(&mut self, line: &Line) -> Vec<&dyn Tile> {
+        for location in line {
+            self.tile_mut(location);
+        }
+
+        line.iter()
+            .map(|location| &self.tiles[location] as &dyn Tile)
+            .collect()
+    }
+
+    fn compact(&mut self) {
+        self.tiles
+            .retain(|_, t| t.piece.is_some() || !t.attributes.is_empty());
+    }
+
+    fn occupied_tiles(&self) -> &HashSet<Box<dyn Tile>> {
+        &self.occupied
+    }
+
+    /// The attributes of every [`Tile`] in this set are returned, not only those at the given
+    /// locations.
+    fn attributes(
+        &self,
+        _locations: &HashSet<Location>,
+    ) -> &HashMap<Location, Vec<Box<dyn TileAttribute>>> {
+        &self.attributes
+    }
+}
+
+#[derive(Debug)]
 pub enum ErrorKind {
     InvalidPlacement,
 }
 
+#[derive(Debug)]
 pub struct Error {
     pub kind: ErrorKind,
     pub message: String,
 }
 
-#[derive(Clone, Debug, Eq, Hash)]
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Clone, Debug, Eq)]
 pub struct PlacementImpl {
     start_location: Location,
     orientation: Box<dyn Orientation>,
@@ -255,13 +1472,87 @@ impl PlacementImpl {
             pieces,
         }
     }
+
+    /// Create a placement that lays the given pieces along the given [`Line`] in the order it is
+    /// walked, starting from its start location. The orientation is the one whose step (see
+    /// [`Orientation::vector`]) matches the step between consecutive locations of the line, so a
+    /// line walked backward produces a reversed orientation; a single location line is treated as
+    /// being along the x-axis.
+    ///
+    /// An error is returned if the line does not take the same step between every pair of
+    /// consecutive locations or if the number of pieces does not match the number of locations on
+    /// the line.
+    pub fn along_line(line: &Line, pieces: Vec<Box<dyn Piece>>) -> Result<PlacementImpl, Error> {
+        let path = line.ordered_locations();
+        let step = match path.as_slice() {
+            [first, second, ..] => Vector::from(first, second),
+            _ => Orientations::x().vector(),
+        };
+        if path
+            .windows(2)
+            .any(|pair| Vector::from(&pair[0], &pair[1]) != step)
+        {
+            return Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: String::from("Line does not follow a single orientation"),
+            });
+        }
+
+        if pieces.len() != path.len() {
+            return Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: format!(
+                    "Line has {} locations but {} pieces were given",
+                    path.len(),
+                    pieces.len()
+                ),
+            });
+        }
+
+        Ok(PlacementImpl::new(
+            path[0],
+            <dyn Orientation>::from_vector(&step),
+            pieces,
+        ))
+    }
+
+    /// Assign the given [`Letter`] to the wildcard [`Piece`] at the given index of this placement
+    /// (see [`Piece::set_letter`]). The piece remains a wildcard and keeps its value, so scoring
+    /// still treats it as worth 0 rather than the value of the assigned letter.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidPlacement`] if there is no piece at the given
+    /// index or that piece is not a wildcard.
+    pub fn assign_wild(&mut self, index: usize, letter: Box<dyn Letter>) -> Result<(), Error> {
+        match self.pieces.get_mut(index) {
+            Some(piece) if piece.wild() => {
+                piece.set_letter(Some(letter));
+                Ok(())
+            }
+            Some(_) => Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: format!("Piece at index {} is not a wildcard", index),
+            }),
+            None => Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: format!("Placement has no piece at index {}", index),
+            }),
+        }
+    }
+}
+
+impl Hash for PlacementImpl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start_location.hash(state);
+        self.orientation.hash(state);
+        self.pieces.hash(state);
+    }
 }
 
 impl PartialEq for PlacementImpl {
     fn eq(&self, other: &Self) -> bool {
-        (&self.start_location).eq(&other.start_location)
-            && (&self.orientation).eq(&other.orientation)
-            && (&self.pieces).eq(&other.pieces)
+        self.start_location.eq(&other.start_location)
+            && self.orientation.eq(&other.orientation)
+            && self.pieces.eq(&other.pieces)
     }
 }
 
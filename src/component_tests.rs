@@ -14,66 +14,1609 @@
  * limitations under the License.
  */
 
-use std::fmt::{Display, Formatter, Result};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Display, Formatter, Result},
+    hash::{Hash, Hasher},
+    thread,
+};
+
+use dyn_clone::clone_box;
+use indexmap::IndexSet;
+use rstest::rstest;
+
+use crate::{
+    component::{
+        anchors, cross_checks, first_move_covers_start, generate_moves, is_connected, render,
+        score_placement, score_placement_with_bonus, validate_overlap, Board, BoardImpl, Error,
+        ErrorKind, LetterMultiplier, LockedPiece, Piece, Placement, PlacementImpl, Rack,
+        RadiusBonus, Tile, TileAttribute, TileSet, TileSetImpl, WordMultiplier, WORD_PLACEHOLDER,
+    },
+    dictionary::HashSetDictionary,
+    lang::{Alphabets, Collation, Letter},
+    space::{Dimension, Distance, Line, Location, Orientation, Orientations, Vector},
+};
+
+#[rstest]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 0, true), true)]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 1, true), true)]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 0, true), true)]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 1, false), false)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 1, false), true)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 2, false), true)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::None, 1, false), false)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('B'), 1, false), false)]
+fn piece_eq(#[case] lhs: Box<dyn Piece>, #[case] rhs: Box<dyn Piece>, #[case] expected: bool) {
+    // when
+    let result = lhs.eq(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 0, true), true)]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 1, true), true)]
+#[case(new_piece(Option::Some('A'), 0, true), new_piece(Option::Some('A'), 0, true), true)]
+#[case(new_piece(Option::Some('A'), 0, true), new_piece(Option::Some('B'), 0, true), false)]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 0, true), false)]
+#[case(new_piece(Option::Some('A'), 0, true), new_piece(Option::Some('A'), 1, false), false)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 2, false), true)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('B'), 1, false), false)]
+fn piece_eq_exact(
+    #[case] lhs: Box<dyn Piece>,
+    #[case] rhs: Box<dyn Piece>,
+    #[case] expected: bool,
+) {
+    // when
+    let result = lhs.eq_exact(&*rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn piece_eq_exact_resolved_wildcards() {
+    // given
+    let lhs: Box<dyn Piece> = new_piece(Option::Some('A'), 0, true);
+    let rhs: Box<dyn Piece> = new_piece(Option::Some('B'), 0, true);
+
+    // when
+    let lenient = lhs.eq(&rhs);
+    let exact = lhs.eq_exact(&*rhs);
+
+    // then
+    assert!(lenient);
+    assert!(!exact);
+}
+
+#[rstest]
+#[case(
+    new_piece(Option::Some('A'), 0, true),
+    new_piece(Option::Some('B'), 2, true)
+)]
+#[case(
+    new_piece(Option::Some('A'), 1, false),
+    new_piece(Option::Some('A'), 2, false)
+)]
+fn piece_hash_agrees_with_eq(#[case] lhs: Box<dyn Piece>, #[case] rhs: Box<dyn Piece>) {
+    // given
+    let mut pieces: HashSet<Box<dyn Piece>> = HashSet::new();
+    pieces.insert(lhs);
+
+    // when
+    let result = pieces.contains(&rhs);
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 0, true), Ordering::Equal)]
+#[case(new_piece(Option::Some('Z'), 10, false), new_piece(Option::None, 0, true), Ordering::Less)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('B'), 1, false), Ordering::Less)]
+#[case(new_piece(Option::Some('B'), 1, false), new_piece(Option::Some('A'), 1, false), Ordering::Greater)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 2, false), Ordering::Less)]
+#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 1, false), Ordering::Equal)]
+#[case(new_piece(Option::None, 2, true), new_piece(Option::None, 0, true), Ordering::Greater)]
+#[case(new_piece(Option::Some('B'), 0, false), new_piece(Option::Some('A'), 5, false), Ordering::Greater)]
+#[case(new_piece(Option::None, 1, false), new_piece(Option::Some('A'), 1, false), Ordering::Less)]
+fn piece_display_cmp(
+    #[case] lhs: Box<dyn Piece>,
+    #[case] rhs: Box<dyn Piece>,
+    #[case] expected: Ordering,
+) {
+    // when
+    let result = lhs.display_cmp(&*rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn piece_sort_rack() {
+    // given
+    let mut rack: Vec<Box<dyn Piece>> = vec![
+        new_piece(Option::None, 0, true),
+        new_piece(Option::Some('T'), 1, false),
+        new_piece(Option::Some('E'), 2, false),
+        new_piece(Option::Some('Q'), 10, false),
+        new_piece(Option::Some('E'), 1, false),
+        new_piece(Option::None, 0, true),
+        new_piece(Option::Some('A'), 1, false),
+    ];
+
+    // when
+    rack.sort_by(|a, b| a.display_cmp(&**b));
+
+    // then
+    let result: Vec<(Option<char>, i32, bool)> = rack
+        .iter()
+        .map(|p| {
+            (
+                p.letter().as_ref().map(|l| l.character()),
+                p.value(),
+                p.wild(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        result,
+        vec![
+            (Option::Some('A'), 1, false),
+            (Option::Some('E'), 1, false),
+            (Option::Some('E'), 2, false),
+            (Option::Some('Q'), 10, false),
+            (Option::Some('T'), 1, false),
+            (Option::None, 0, true),
+            (Option::None, 0, true),
+        ]
+    );
+}
+
+#[test]
+fn piece_send() {
+    // given
+    let piece: Box<dyn Piece> = new_piece(Option::Some('A'), 1, false);
+
+    // when
+    let result = thread::spawn(move || piece.letter().as_ref().map(|l| l.character()))
+        .join()
+        .unwrap();
+
+    // then
+    assert_eq!(result, Option::Some('A'));
+}
+
+#[test]
+fn locked_piece_delegates() {
+    // given
+    let piece = new_piece(Option::Some('A'), 1, false);
+
+    // when
+    let result = LockedPiece::new(piece.clone());
+
+    // then
+    assert_eq!(result.letter(), piece.letter());
+    assert_eq!(result.value(), piece.value());
+    assert_eq!(result.wild(), piece.wild());
+}
+
+#[test]
+fn locked_piece_set_letter() {
+    // given
+    let mut piece = LockedPiece::new(new_piece(Option::Some('A'), 0, true));
+
+    // when
+    piece.set_letter(Option::Some(Box::new(TestLetter { character: 'B' })));
+
+    // then
+    assert_eq!(
+        piece.letter().as_ref().map(|l| l.character()),
+        Option::Some('A')
+    );
+}
+
+#[rstest]
+#[case(Box::new(TestBoard::new(Dimension::of((15, 15)))))]
+#[case(Box::new(new_board(Dimension::of((15, 15)))))]
+fn board_place_locks_pieces(#[case] mut board: Box<dyn Board>) {
+    // given
+    let placement = PlacementImpl::new(
+        Location::at((7, 7, 0)),
+        Orientations::x(),
+        vec![new_piece(Option::Some('A'), 0, true)],
+    );
+    board.place(Box::new(placement)).unwrap();
+
+    // when
+    let mut result = board
+        .tiles()
+        .occupied_tiles()
+        .iter()
+        .find_map(|t| t.piece().map(clone_box))
+        .unwrap();
+    result.set_letter(Option::Some(Box::new(TestLetter { character: 'B' })));
+
+    // then
+    assert_eq!(
+        result.letter().as_ref().map(|l| l.character()),
+        Option::Some('A')
+    );
+}
+
+#[test]
+fn rack_add() {
+    // given
+    let mut rack = Rack::new();
+
+    // when
+    rack.add(new_piece(Option::Some('A'), 1, false));
+    rack.add(new_piece(Option::None, 0, true));
+
+    // then
+    assert_eq!(rack.len(), 2);
+    assert!(!rack.is_empty());
+    assert!(rack.pieces()[0].eq_exact(&*new_piece(Option::Some('A'), 1, false)));
+    assert!(rack.pieces()[1].wild());
+}
+
+#[rstest]
+#[case(new_piece(Option::Some('B'), 1, false), true, 2)]
+#[case(new_piece(Option::Some('B'), 5, false), true, 2)]
+#[case(new_piece(Option::Some('A'), 0, true), true, 2)]
+#[case(new_piece(Option::Some('C'), 1, false), false, 3)]
+fn rack_remove(#[case] piece: Box<dyn Piece>, #[case] found: bool, #[case] remaining: usize) {
+    // given
+    let mut rack = Rack::new();
+    rack.add(new_piece(Option::Some('A'), 1, false));
+    rack.add(new_piece(Option::Some('B'), 1, false));
+    rack.add(new_piece(Option::None, 0, true));
+
+    // when
+    let result = rack.remove(&*piece);
+
+    // then
+    assert_eq!(result.is_some(), found);
+    assert!(result.is_none_or(|p| p.as_ref() == piece.as_ref()));
+    assert_eq!(rack.len(), remaining);
+}
+
+#[rstest]
+#[case("CAT", true)]
+#[case("cat", true)]
+#[case("TACT", true)]
+#[case("TACTS", false)]
+#[case("BAT", true)]
+#[case("BOAT", false)]
+#[case("", true)]
+fn rack_can_spell(#[case] word: &str, #[case] expected: bool) {
+    // given
+    let mut rack = Rack::new();
+    rack.add(new_piece(Option::Some('C'), 3, false));
+    rack.add(new_piece(Option::Some('A'), 1, false));
+    rack.add(new_piece(Option::Some('T'), 1, false));
+    rack.add(new_piece(Option::None, 0, true));
+    let word: Vec<char> = word.chars().collect();
+
+    // when
+    let result = rack.can_spell(&word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Option::None, "?BCDÇ?")]
+#[case(Option::Some(SequenceCollation { sequence: vec!['B', 'C', DIGRAPH_CH, 'D'] }), "?BCÇD?")]
+fn rack_sort(#[case] collation: Option<SequenceCollation>, #[case] expected: &str) {
+    // given
+    let mut rack = Rack::new();
+    rack.add(new_piece(Option::Some('D'), 2, false));
+    rack.add(new_piece(Option::None, 0, true));
+    rack.add(new_piece(Option::Some(DIGRAPH_CH), 5, false));
+    rack.add(new_piece(Option::Some('C'), 3, false));
+    rack.add(new_piece(Option::Some('B'), 3, false));
+    rack.add(new_piece(Option::None, 0, false));
+
+    // when
+    rack.sort(collation.as_ref().map(|c| c as &dyn Collation));
+
+    // then
+    let result: String = rack
+        .pieces()
+        .iter()
+        .map(|p| p.letter().as_ref().map_or('?', |l| l.character()))
+        .collect();
+    assert_eq!(result, expected);
+    assert!(rack.pieces()[5].wild());
+}
+
+#[rstest]
+#[case(0, 10, 7, 7)]
+#[case(5, 10, 2, 7)]
+#[case(0, 3, 3, 3)]
+#[case(7, 10, 0, 7)]
+fn rack_refill(
+    #[case] held: usize,
+    #[case] available: usize,
+    #[case] expected_added: usize,
+    #[case] expected_len: usize,
+) {
+    // given
+    let mut rack = Rack::new();
+    for _ in 0..held {
+        rack.add(new_piece(Option::Some('A'), 1, false));
+    }
+    let mut bag = (0..available)
+        .map(|_| new_piece(Option::Some('B'), 1, false) as Box<dyn Piece>)
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    // when
+    let result = rack.refill(7, &mut bag);
+
+    // then
+    assert_eq!(result, expected_added);
+    assert_eq!(rack.len(), expected_len);
+    assert_eq!(rack.is_full(7), expected_len == 7);
+    assert_eq!(bag.len(), available - expected_added);
+}
+
+#[test]
+fn placement_impl_new() {
+    // given
+    let start_location = Location::at((0, 0, 0));
+    let orientation = Orientations::x();
+    let pieces: Vec<Box<dyn Piece>> = vec![new_piece(Option::Some('A'), 1, false)];
+
+    // when
+    let result = PlacementImpl::new(start_location, orientation.clone(), pieces.clone());
+
+    // then
+    assert_eq!(result.start_location(), &start_location);
+    assert_eq!(result.orientation(), &*orientation);
+    assert_eq!(result.pieces(), &pieces);
+}
+
+#[rstest]
+#[case(Location::at((1, 1, 1)), Location::at((3, 1, 1)), Orientations::x())]
+#[case(Location::at((3, 1, 1)), Location::at((1, 1, 1)), Orientations::x().reverse())]
+#[case(Location::at((1, 1, 1)), Location::at((1, 3, 1)), Orientations::y())]
+#[case(Location::at((1, 1, 1)), Location::at((1, 1, 3)), Orientations::z())]
+#[case(Location::at((1, 1, 1)), Location::at((3, 3, 1)), Orientations::xy_diagonal())]
+#[case(Location::at((1, 1, 1)), Location::at((3, 3, 3)), Orientations::xyz_diagonal())]
+fn placement_impl_along_line(
+    #[case] start: Location,
+    #[case] end: Location,
+    #[case] orientation: Box<dyn Orientation>,
+) {
+    // given
+    let line = Line::between(&start, &end);
+    let pieces: Vec<Box<dyn Piece>> = vec![
+        new_piece(Option::Some('A'), 1, false),
+        new_piece(Option::Some('B'), 1, false),
+        new_piece(Option::Some('C'), 1, false),
+    ];
+
+    // when
+    let result = PlacementImpl::along_line(&line, pieces.clone()).unwrap();
+
+    // then
+    assert_eq!(result.start_location(), &start);
+    assert_eq!(result.orientation(), &*orientation);
+    assert_eq!(result.pieces(), &pieces);
+    assert_eq!(result.locations(), line.ordered_locations());
+}
+
+#[test]
+fn placement_impl_along_line_point() {
+    // given
+    let line = Line::between(&Location::at((1, 1, 1)), &Location::at((1, 1, 1)));
+
+    // when
+    let result =
+        PlacementImpl::along_line(&line, vec![new_piece(Option::Some('A'), 1, false)]).unwrap();
+
+    // then
+    assert_eq!(result.start_location(), &Location::at((1, 1, 1)));
+    assert_eq!(result.orientation(), &*Orientations::x());
+}
+
+#[rstest]
+#[case(Location::at((1, 1, 1)), Location::at((3, 1, 1)), 2)]
+#[case(Location::at((1, 1, 1)), Location::at((3, 1, 1)), 4)]
+#[case(Location::at((1, 1, 1)), Location::at((4, 2, 1)), 4)]
+fn placement_impl_along_line_invalid(
+    #[case] start: Location,
+    #[case] end: Location,
+    #[case] count: usize,
+) {
+    // given
+    let line = Line::between(&start, &end);
+    let pieces: Vec<Box<dyn Piece>> = (0..count)
+        .map(|_| new_piece(Option::Some('A'), 1, false) as Box<dyn Piece>)
+        .collect();
+
+    // when
+    let result = PlacementImpl::along_line(&line, pieces);
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidPlacement)));
+}
+
+#[rstest]
+#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), true)]
+#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((2, 2, 2)), Orientations::x(), vec![]), false)]
+#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::y(), vec![]), false)]
+#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![new_piece(Option::Some('A'), 1, false)]), false)]
+fn placement_impl_eq(
+    #[case] lhs: PlacementImpl,
+    #[case] rhs: PlacementImpl,
+    #[case] expected: bool,
+) {
+    // when
+    let result = lhs.eq(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(vec![(Option::Some('C'), false), (Option::Some('A'), false), (Option::Some('T'), false)], "CAT")]
+#[case(vec![(Option::Some('C'), false), (Option::None, true), (Option::Some('T'), false)], "C?T")]
+#[case(vec![(Option::Some('C'), false), (Option::Some('A'), true), (Option::Some('T'), false)], "CAT")]
+#[case(vec![(Option::None, false), (Option::Some('A'), false)], "?A")]
+#[case(vec![], "")]
+fn placement_word(#[case] letters: Vec<(Option<char>, bool)>, #[case] expected: &str) {
+    // given
+    let pieces: Vec<Box<dyn Piece>> = letters
+        .into_iter()
+        .map(|(c, wild)| new_piece(c, 1, wild) as Box<dyn Piece>)
+        .collect();
+    let placement = PlacementImpl::new(Location::at((0, 0, 0)), Orientations::x(), pieces);
+
+    // when
+    let result = placement.word();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn placement_word_placeholder() {
+    // given
+    let placement = PlacementImpl::new(
+        Location::at((0, 0, 0)),
+        Orientations::x(),
+        vec![new_piece(Option::None, 0, true)],
+    );
+
+    // when
+    let result = placement.word();
+
+    // then
+    assert_eq!(result, WORD_PLACEHOLDER.to_string());
+}
+
+#[test]
+fn placement_assign_wild() {
+    // given
+    let mut placement = PlacementImpl::new(
+        Location::at((0, 0, 0)),
+        Orientations::x(),
+        vec![
+            new_piece(Option::Some('C'), 3, false),
+            new_piece(Option::None, 0, true),
+            new_piece(Option::Some('T'), 1, false),
+        ],
+    );
+
+    // when
+    let result = placement.assign_wild(1, Box::new(TestLetter { character: 'A' }));
+
+    // then
+    assert!(result.is_ok());
+    assert_eq!(placement.word(), "CAT");
+    assert!(placement.pieces()[1].wild());
+    assert_eq!(placement.pieces()[1].value(), 0);
+}
+
+#[rstest]
+#[case(0)]
+#[case(2)]
+fn placement_assign_wild_invalid(#[case] index: usize) {
+    // given
+    let mut placement = PlacementImpl::new(
+        Location::at((0, 0, 0)),
+        Orientations::x(),
+        vec![
+            new_piece(Option::Some('C'), 3, false),
+            new_piece(Option::None, 0, true),
+        ],
+    );
+
+    // when
+    let result = placement.assign_wild(index, Box::new(TestLetter { character: 'A' }));
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidPlacement)));
+    assert_eq!(placement.word(), format!("C{}", WORD_PLACEHOLDER));
+}
+
+#[rstest]
+#[case(Location::at((2, 0, 0)), Orientations::y(), 4, vec![Location::at((2, 0, 0)), Location::at((2, 1, 0)), Location::at((2, 2, 0)), Location::at((2, 3, 0))])]
+#[case(Location::at((1, 1, 1)), Orientations::x(), 2, vec![Location::at((1, 1, 1)), Location::at((2, 1, 1))])]
+#[case(Location::at((1, 1, 1)), Orientations::xyz_diagonal(), 2, vec![Location::at((1, 1, 1)), Location::at((2, 2, 2))])]
+#[case(Location::at((1, 1, 1)), Orientations::x(), 0, vec![])]
+fn placement_locations(
+    #[case] start: Location,
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] count: usize,
+    #[case] expected: Vec<Location>,
+) {
+    // given
+    let pieces: Vec<Box<dyn Piece>> = (0..count)
+        .map(|_| new_piece(Option::Some('A'), 1, false) as Box<dyn Piece>)
+        .collect();
+    let placement = PlacementImpl::new(start, orientation, pieces);
+
+    // when
+    let result = placement.locations();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((1, 1, 1)), Orientations::x(), 3, Location::at((3, 1, 1)))]
+#[case(Location::at((1, 1, 1)), Orientations::y(), 2, Location::at((1, 2, 1)))]
+#[case(Location::at((1, 1, 1)), Orientations::z(), 4, Location::at((1, 1, 4)))]
+#[case(Location::at((1, 1, 1)), Orientations::x(), 1, Location::at((1, 1, 1)))]
+#[case(Location::at((1, 1, 1)), Orientations::x(), 0, Location::at((1, 1, 1)))]
+fn placement_end_location(
+    #[case] start: Location,
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] count: usize,
+    #[case] expected: Location,
+) {
+    // given
+    let pieces: Vec<Box<dyn Piece>> = (0..count)
+        .map(|_| new_piece(Option::Some('A'), 1, false) as Box<dyn Piece>)
+        .collect();
+    let placement = PlacementImpl::new(start, orientation, pieces);
+
+    // when
+    let result = placement.end_location();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Distance::of((0, 0, 0)), true, 6)]
+#[case(Distance::of((1, 0, 0)), true, 6)]
+#[case(Distance::of((1, 1, 0)), false, 6)]
+#[case(Distance::of((0, 0, 1)), false, 6)]
+#[case(Distance::of((2, 0, 0)), true, 2)]
+#[case(Distance::of((1, 2, 0)), false, 2)]
+fn radius_bonus_modify_value(
+    #[case] distance: Distance,
+    #[case] same_word: bool,
+    #[case] expected: i32,
+) {
+    // given
+    let attribute = RadiusBonus::new(1, 3);
+
+    // when
+    let result = attribute.modify_value(2, &distance, same_word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn radius_bonus_radius() {
+    // given
+    let attribute = RadiusBonus::new(2, 3);
+
+    // when
+    let result = attribute.radius();
+
+    // then
+    assert_eq!(result, 2);
+    assert_eq!(LetterMultiplier::new(2).radius(), 0);
+    assert_eq!(WordMultiplier::new(2).radius(), 0);
+}
+
+#[test]
+fn radius_bonus_visible() {
+    // given
+    let attribute = RadiusBonus::new(1, 3);
+
+    // when
+    let result = attribute.visible();
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case(Distance::of((0, 0, 0)), true, 6)]
+#[case(Distance::of((0, 0, 0)), false, 6)]
+#[case(Distance::of((1, 0, 0)), true, 3)]
+#[case(Distance::of((0, 1, 0)), false, 3)]
+#[case(Distance::of((0, 0, 1)), true, 3)]
+fn letter_multiplier_modify_value(
+    #[case] distance: Distance,
+    #[case] same_word: bool,
+    #[case] expected: i32,
+) {
+    // given
+    let attribute = LetterMultiplier::new(2);
+
+    // when
+    let result = attribute.modify_value(3, &distance, same_word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn letter_multiplier_visible() {
+    // given
+    let attribute = LetterMultiplier::new(2);
+
+    // when
+    let result = attribute.visible();
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case(Distance::of((0, 0, 0)), true, 6)]
+#[case(Distance::of((3, 0, 0)), true, 6)]
+#[case(Distance::of((0, 0, 0)), false, 2)]
+#[case(Distance::of((0, 1, 0)), false, 2)]
+fn word_multiplier_modify_value(
+    #[case] distance: Distance,
+    #[case] same_word: bool,
+    #[case] expected: i32,
+) {
+    // given
+    let attribute = WordMultiplier::new(3);
+
+    // when
+    let result = attribute.modify_value(2, &distance, same_word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn word_multiplier_visible() {
+    // given
+    let attribute = WordMultiplier::new(3);
+
+    // when
+    let result = attribute.visible();
+
+    // then
+    assert!(result);
+}
+
+#[test]
+fn word_multiplier_after_letter_multiplier() {
+    // given
+    let letter = LetterMultiplier::new(2);
+    let word = WordMultiplier::new(3);
+    let values = [(1, Distance::of((0, 0, 0))), (4, Distance::of((1, 0, 0)))];
+
+    // when
+    let result: i32 = values
+        .iter()
+        .map(|(v, d)| word.modify_value(letter.modify_value(*v, d, true), d, true))
+        .sum();
+
+    // then
+    let total: i32 = values
+        .iter()
+        .map(|(v, d)| letter.modify_value(*v, d, true))
+        .sum();
+    assert_eq!(result, total * 3);
+    assert_eq!(result, 18);
+}
+
+#[rstest]
+#[case(RadiusBonus::new(1, 2), RadiusBonus::new(1, 2), true)]
+#[case(RadiusBonus::new(1, 2), RadiusBonus::new(1, 3), false)]
+#[case(RadiusBonus::new(1, 2), RadiusBonus::new(2, 2), false)]
+fn tile_attribute_eq(#[case] lhs: RadiusBonus, #[case] rhs: RadiusBonus, #[case] expected: bool) {
+    // given
+    let lhs: Box<dyn TileAttribute> = Box::new(lhs);
+    let rhs: Box<dyn TileAttribute> = Box::new(rhs);
+
+    // when
+    let result = lhs.eq(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn tile_attribute_set() {
+    // given
+    let mut attributes: HashSet<Box<dyn TileAttribute>> = HashSet::new();
+
+    // when
+    attributes.insert(Box::new(RadiusBonus::new(1, 2)));
+    attributes.insert(Box::new(RadiusBonus::new(1, 2)));
+    attributes.insert(Box::new(RadiusBonus::new(1, 3)));
+
+    // then
+    assert_eq!(attributes.len(), 2);
+}
+
+#[test]
+fn tile_set_piece() {
+    // given
+    let mut tile = TestTile::new(Location::at((1, 1, 0)));
+
+    // when
+    tile.set_piece(new_piece(Option::Some('A'), 3, false));
+
+    // then
+    let expected: Box<dyn Piece> = new_piece(Option::Some('A'), 3, false);
+    assert!(tile.piece().is_some_and(|p| p.eq_exact(&*expected)));
+    assert_eq!(tile.base_value(), 3);
+}
+
+#[test]
+fn tile_add_remove_attribute() {
+    // given
+    let mut tile = TestTile::new(Location::at((1, 1, 0)));
+
+    // when
+    tile.add_attribute(Box::new(RadiusBonus::new(1, 2)));
+    tile.add_attribute(Box::new(RadiusBonus::new(0, 3)));
+    tile.remove_attribute(&RadiusBonus::new(1, 2));
+
+    // then
+    let expected: Box<dyn TileAttribute> = Box::new(RadiusBonus::new(0, 3));
+    assert_eq!(tile.attributes().len(), 1);
+    assert!(tile.attributes().contains(&expected));
+}
+
+#[test]
+fn tile_set_compact() {
+    // given
+    let mut tiles = TileSetImpl::new();
+    tiles.set_piece(
+        &Location::at((1, 0, 0)),
+        new_piece(Option::Some('A'), 1, false),
+    );
+    tiles.add_attribute(&Location::at((2, 0, 0)), Box::new(RadiusBonus::new(0, 2)));
+    tiles.tile(&Location::at((3, 0, 0)));
+    tiles.tile(&Location::at((4, 0, 0)));
+
+    // when
+    tiles.compact();
+
+    // then
+    assert_eq!(tiles.len(), 2);
+    assert!(tiles.piece(&Location::at((1, 0, 0))).is_some());
+    assert_eq!(tiles.tile(&Location::at((2, 0, 0))).attributes().len(), 1);
+    assert_eq!(tiles.occupied_tiles().len(), 1);
+}
+
+#[test]
+fn tile_set_remove_piece() {
+    // given
+    let location = Location::at((1, 0, 0));
+    let mut tiles = TileSetImpl::new();
+    tiles.set_piece(&location, new_piece(Option::Some('A'), 1, false));
+
+    // when
+    let removed = tiles.remove_piece(&location);
+    let removed_again = tiles.remove_piece(&location);
+
+    // then
+    assert!(removed.is_some_and(|p| p.value() == 1));
+    assert!(removed_again.is_none());
+    assert!(tiles.piece(&location).is_none());
+    assert!(tiles.occupied_tiles().is_empty());
+}
+
+#[rstest]
+#[case(1)]
+#[case(42)]
+#[case(1234)]
+fn tile_set_bounded_matches_unbounded(#[case] seed: u64) {
+    // given
+    let dimension = Dimension::of((9, 8, 2));
+    let mut bounded = TileSetImpl::bounded(&dimension).unwrap();
+    let mut unbounded = TileSetImpl::new();
+    let mut state = seed;
+    let mut next = |bound: u32| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % (bound as u64 + 2)) as i32 - 1
+    };
+
+    // when
+    for _ in 0..300 {
+        let location = Location::at((
+            next(dimension.width()),
+            next(dimension.height()),
+            next(dimension.depth()),
+        ));
+        if next(1) == 0 {
+            bounded.remove_piece(&location);
+            unbounded.remove_piece(&location);
+        } else {
+            bounded.set_piece(&location, new_piece(Option::Some('A'), 1, false));
+            unbounded.set_piece(&location, new_piece(Option::Some('A'), 1, false));
+        }
+    }
+
+    // then
+    assert_eq!(
+        bounded.occupied_tiles().len(),
+        unbounded.occupied_tiles().len()
+    );
+    for x in -1..=dimension.width() as i32 {
+        for y in -1..=dimension.height() as i32 {
+            for z in -1..=dimension.depth() as i32 {
+                let location = Location::at((x, y, z));
+                assert_eq!(
+                    bounded.is_occupied(&location),
+                    unbounded.is_occupied(&location),
+                    "{location:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tile_set_bounded_clear() {
+    // given
+    let location = Location::at((1, 1, 0));
+    let mut tiles = TileSetImpl::bounded(&Dimension::of((3, 3))).unwrap();
+    tiles.set_piece(&location, new_piece(Option::Some('A'), 1, false));
+
+    // when
+    tiles.clear();
+
+    // then
+    assert!(!tiles.is_occupied(&location));
+}
+
+#[test]
+fn tile_set_bounded_too_large() {
+    // when
+    let result = TileSetImpl::bounded(&Dimension::of((4096, 4096, 2)));
+
+    // then
+    assert!(result.is_err());
+}
+
+#[rstest]
+#[case(Location::at((2, 4, 0)), Location::at((4, 4, 0)))]
+#[case(Location::at((4, 4, 0)), Location::at((2, 4, 0)))]
+fn tile_set_tiles_in_line(#[case] start: Location, #[case] end: Location) {
+    // given
+    let mut tiles = TileSetImpl::new();
+    tiles.set_piece(
+        &Location::at((3, 4, 0)),
+        new_piece(Option::Some('A'), 1, false),
+    );
+    let line = Line::between(&start, &end);
+
+    // when
+    let result = tiles.tiles_in_line(&line);
+
+    // then
+    let locations: Vec<Location> = result.iter().map(|t| *t.location()).collect();
+    assert_eq!(locations, line.iter().copied().collect::<Vec<_>>());
+    assert_eq!(locations[0], start);
+    assert!(result[0].piece().is_none());
+    assert!(result[1].piece().is_some());
+    assert!(result[2].piece().is_none());
+    assert_eq!(tiles.len(), 3);
+}
+
+#[test]
+fn board_place() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    let placement: Box<dyn Placement> = Box::new(PlacementImpl::new(
+        Location::at((7, 7, 0)),
+        Orientations::x(),
+        vec![
+            new_piece(Option::Some('C'), 3, false),
+            new_piece(Option::Some('A'), 1, false),
+            new_piece(Option::Some('T'), 1, false),
+        ],
+    ));
+
+    // when
+    let result = board.place(placement.clone());
+
+    // then
+    assert!(matches!(result, Ok(5)));
+    assert_eq!(board.placements, vec![placement.clone()]);
+    assert_eq!(board.tiles().occupied_tiles().len(), 3);
+    for (i, piece) in placement.pieces().iter().enumerate() {
+        let location = Location::at((7 + i as i32, 7, 0));
+        let tile = board.tiles.tiles.get(&location).unwrap();
+        assert!(tile.piece().is_some_and(|p| p.eq_exact(&**piece)));
+    }
+}
+
+#[rstest]
+#[case(Location::at((5, 7, 0)), Orientations::x())]
+#[case(Location::at((7, 5, 0)), Orientations::y())]
+#[case(Location::at((13, 0, 0)), Orientations::x())]
+#[case(Location::at((0, 0, 0)), Orientations::z())]
+fn board_place_invalid(#[case] start: Location, #[case] orientation: Box<dyn Orientation>) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(PlacementImpl::new(
+            Location::at((7, 7, 0)),
+            Orientations::x(),
+            vec![new_piece(Option::Some('A'), 1, false)],
+        )))
+        .unwrap();
+    let placement = PlacementImpl::new(
+        start,
+        orientation,
+        vec![
+            new_piece(Option::Some('B'), 1, false),
+            new_piece(Option::Some('C'), 1, false),
+            new_piece(Option::Some('D'), 1, false),
+        ],
+    );
+
+    // when
+    let result = board.place(Box::new(placement));
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidPlacement)));
+    assert_eq!(board.placements.len(), 1);
+    assert_eq!(board.tiles().occupied_tiles().len(), 1);
+}
+
+#[test]
+fn board_remove_placement() {
+    // given
+    let mut board = new_board(Dimension::of((15, 15)));
+    let placement = new_placement((7, 7), Orientations::x(), "CAT");
+    board.place(Box::new(placement.clone())).unwrap();
+
+    // when
+    let result = board.remove_placement(&placement);
+
+    // then
+    assert!(result.is_ok());
+    assert!(board.placements().is_empty());
+    assert!(board.tiles().occupied_tiles().is_empty());
+    assert!(board.valid(&placement));
+}
+
+#[rstest]
+#[case((7, 7), Orientations::x(), "DOG")]
+#[case((7, 7), Orientations::y(), "CAT")]
+#[case((8, 7), Orientations::x(), "AT")]
+fn board_remove_placement_absent(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+) {
+    // given
+    let mut board = new_board(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+
+    // when
+    let result = board.remove_placement(&new_placement(start, orientation, word));
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidPlacement)));
+    assert_eq!(board.placements().len(), 1);
+    assert_eq!(board.tiles().occupied_tiles().len(), 3);
+}
+
+#[rstest]
+#[case((7, 7), Orientations::x(), "CAT", true)]
+#[case((5, 7), Orientations::x(), "CAT", true)]
+#[case((1, 1), Orientations::x(), "CAT", false)]
+#[case((7, 7), Orientations::z(), "CAT", false)]
+#[case((13, 7), Orientations::x(), "CAT", false)]
+fn board_impl_valid_first_move(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+    #[case] expected: bool,
+) {
+    // given
+    let board = new_board(Dimension::of((15, 15)));
+
+    // when
+    let result = board.valid(&new_placement(start, orientation, word));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case((9, 8), Orientations::y(), "AT", true)]
+#[case((7, 8), Orientations::x(), "AT", true)]
+#[case((7, 7), Orientations::y(), "AT", false)]
+#[case((1, 1), Orientations::x(), "AT", false)]
+fn board_impl_valid_later_move(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+    #[case] expected: bool,
+) {
+    // given
+    let mut board = new_board(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+
+    // when
+    let result = board.valid(&new_placement(start, orientation, word));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn board_render() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((4, 3)));
+    board
+        .place(Box::new(new_placement((1, 1), Orientations::x(), "CA")))
+        .unwrap();
+
+    // when
+    let result = render(&board);
+
+    // then
+    assert_eq!(result, "....\n.CA.\n....");
+}
+
+#[test]
+fn board_render_layers() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((2, 2, 2)));
+    board.tiles.set_piece(
+        &Location::at((0, 0, 0)),
+        new_piece(Option::Some('A'), 1, false),
+    );
+    board
+        .tiles
+        .set_piece(&Location::at((1, 0, 1)), new_piece(Option::None, 0, true));
+
+    // when
+    let result = render(&board);
+
+    // then
+    assert_eq!(result, "A.\n..\n\n.?\n..");
+}
+
+#[rstest]
+#[case(Box::new(TestBoard::new(Dimension::of((15, 15)))), vec![], BTreeMap::new())]
+#[case(Box::new(new_board(Dimension::of((15, 15)))), vec![], BTreeMap::new())]
+#[case(Box::new(TestBoard::new(Dimension::of((15, 15)))), vec![('Z', 10), ('A', 1), ('P', 3), ('S', 1)], BTreeMap::from([(1, 2), (3, 1), (10, 1)]))]
+#[case(Box::new(new_board(Dimension::of((15, 15)))), vec![('Z', 10), ('A', 1), ('P', 3), ('S', 1)], BTreeMap::from([(1, 2), (3, 1), (10, 1)]))]
+fn board_value_histogram(
+    #[case] mut board: Box<dyn Board>,
+    #[case] pieces: Vec<(char, i32)>,
+    #[case] expected: BTreeMap<i32, usize>,
+) {
+    // given
+    if !pieces.is_empty() {
+        board
+            .place(Box::new(PlacementImpl::new(
+                Location::at((7, 7, 0)),
+                Orientations::x(),
+                pieces
+                    .into_iter()
+                    .map(|(c, v)| new_piece(Option::Some(c), v, false) as Box<dyn Piece>)
+                    .collect(),
+            )))
+            .unwrap();
+    }
+
+    // when
+    let result = board.value_histogram();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(vec![], 5)]
+#[case(vec![((7, 7), Box::new(LetterMultiplier::new(2)) as Box<dyn TileAttribute>)], 8)]
+#[case(vec![((8, 7), Box::new(LetterMultiplier::new(3)) as Box<dyn TileAttribute>)], 7)]
+#[case(vec![((9, 7), Box::new(WordMultiplier::new(3)) as Box<dyn TileAttribute>)], 15)]
+#[case(vec![((7, 7), Box::new(LetterMultiplier::new(2)) as Box<dyn TileAttribute>), ((9, 7), Box::new(WordMultiplier::new(3)))], 24)]
+#[case(vec![((7, 8), Box::new(WordMultiplier::new(3)) as Box<dyn TileAttribute>)], 5)]
+#[case(vec![((6, 7), Box::new(RadiusBonus::new(1, 2)) as Box<dyn TileAttribute>)], 8)]
+#[case(vec![((8, 6), Box::new(RadiusBonus::new(1, 2)) as Box<dyn TileAttribute>)], 10)]
+#[case(vec![((5, 7), Box::new(RadiusBonus::new(1, 2)) as Box<dyn TileAttribute>)], 5)]
+#[case(vec![((11, 7), Box::new(RadiusBonus::new(2, 2)) as Box<dyn TileAttribute>)], 6)]
+#[case(vec![((6, 7), Box::new(LetterMultiplier::new(2)) as Box<dyn TileAttribute>)], 5)]
+fn score_placement_attributes(
+    #[case] attributes: Vec<((i32, i32), Box<dyn TileAttribute>)>,
+    #[case] expected: i32,
+) {
+    // given
+    let mut board = new_board(Dimension::of((15, 15)));
+    for (location, attribute) in attributes {
+        board.add_attribute(&Location::from(location), attribute);
+    }
+    let placement = new_placement((7, 7), Orientations::x(), "CAT");
+
+    // when
+    let result = score_placement(&board, &placement);
+
+    // then
+    assert_eq!(result, expected);
+}
 
-use rstest::rstest;
+#[test]
+fn score_placement_large_board() {
+    // given
+    let mut board = new_board(Dimension::of((4096, 4096)));
+    board.add_attribute(
+        &Location::at((2049, 2047, 0)),
+        Box::new(RadiusBonus::new(1, 2)),
+    );
+    let placement = new_placement((2048, 2048), Orientations::x(), "AT");
 
-use crate::{
-    component::{Piece, Placement, PlacementImpl},
-    lang::Letter,
-    space::{Location, Orientations},
-};
+    // when
+    let result = score_placement(&board, &placement);
+
+    // then
+    assert_eq!(result, 4);
+}
+
+#[test]
+fn score_placement_extends_word() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .tiles
+        .add_attribute(&Location::at((7, 7, 0)), Box::new(WordMultiplier::new(2)));
+    board
+        .tiles
+        .add_attribute(&Location::at((10, 7, 0)), Box::new(WordMultiplier::new(3)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+
+    // when
+    let result = score_placement(&board, &new_placement((10, 7), Orientations::x(), "S"));
+
+    // then
+    assert_eq!(result, 18);
+}
 
 #[rstest]
-#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 0, true), true)]
-#[case(new_piece(Option::None, 0, true), new_piece(Option::None, 1, true), true)]
-#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 0, true), true)]
-#[case(new_piece(Option::None, 0, true), new_piece(Option::Some('A'), 1, false), false)]
-#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 1, false), true)]
-#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('A'), 2, false), true)]
-#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::None, 1, false), false)]
-#[case(new_piece(Option::Some('A'), 1, false), new_piece(Option::Some('B'), 1, false), false)]
-fn piece_eq(#[case] lhs: Box<dyn Piece>, #[case] rhs: Box<dyn Piece>, #[case] expected: bool) {
+#[case(vec![], 8)]
+#[case(vec![((10, 7), Box::new(LetterMultiplier::new(2)) as Box<dyn TileAttribute>)], 10)]
+#[case(vec![((10, 8), Box::new(WordMultiplier::new(3)) as Box<dyn TileAttribute>)], 12)]
+#[case(vec![((10, 7), Box::new(WordMultiplier::new(2)) as Box<dyn TileAttribute>)], 16)]
+fn score_placement_cross_word(
+    #[case] attributes: Vec<((i32, i32), Box<dyn TileAttribute>)>,
+    #[case] expected: i32,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+    for (location, attribute) in attributes {
+        board
+            .tiles
+            .add_attribute(&Location::from(location), attribute);
+    }
+
     // when
-    let result = lhs.eq(&rhs);
+    let result = score_placement(&board, &new_placement((10, 7), Orientations::y(), "SO"));
 
     // then
     assert_eq!(result, expected);
 }
 
 #[test]
-fn placement_impl_new() {
+fn score_placement_single_piece_cross_word() {
     // given
-    let start_location = Location::at((0, 0, 0));
-    let orientation = Orientations::x();
-    let pieces: Vec<Box<dyn Piece>> = vec![new_piece(Option::Some('A'), 1, false)];
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
 
     // when
-    let result = PlacementImpl::new(start_location, orientation.clone(), pieces.clone());
+    let result = score_placement(&board, &new_placement((8, 8), Orientations::x(), "T"));
 
     // then
-    assert_eq!(result.start_location(), &start_location);
-    assert_eq!(result.orientation(), &*orientation);
-    assert_eq!(result.pieces(), &pieces);
+    assert_eq!(result, 2);
 }
 
 #[rstest]
-#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), true)]
-#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((2, 2, 2)), Orientations::x(), vec![]), false)]
-#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::y(), vec![]), false)]
-#[case(PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![]), PlacementImpl::new(Location::at((1, 1, 1)), Orientations::x(), vec![new_piece(Option::Some('A'), 1, false)]), false)]
-fn placement_impl_eq(
-    #[case] lhs: PlacementImpl,
-    #[case] rhs: PlacementImpl,
+#[case("ABDEFGH", 7, 50, 57)]
+#[case("ABDEFG", 7, 50, 6)]
+#[case("ABD", 3, 35, 38)]
+#[case("ABDEFGH", 7, 0, 7)]
+fn score_placement_with_bonus_rack(
+    #[case] word: &str,
+    #[case] rack_size: usize,
+    #[case] bonus: i32,
+    #[case] expected: i32,
+) {
+    // given
+    let board = TestBoard::new(Dimension::of((15, 15)));
+    let placement = new_placement((4, 7), Orientations::x(), word);
+
+    // when
+    let result = score_placement_with_bonus(&board, &placement, rack_size, bonus);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn board_calculate_points() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .tiles
+        .add_attribute(&Location::at((7, 7, 0)), Box::new(LetterMultiplier::new(2)));
+    let placement = new_placement((7, 7), Orientations::x(), "CAT");
+
+    // when
+    let result = board.place(Box::new(placement.clone()));
+
+    // then
+    assert!(matches!(result, Ok(8)));
+    assert_eq!(
+        board.calculate_points(&placement),
+        score_placement(&board, &placement)
+    );
+}
+
+#[test]
+fn anchors_empty_board() {
+    // given
+    let board = TestBoard::new(Dimension::of((15, 15)));
+
+    // when
+    let result = anchors(&board);
+
+    // then
+    assert_eq!(result, HashSet::from([Location::at((7, 7, 0))]));
+}
+
+#[rstest]
+#[case((7, 7), Orientations::x(), "CAT", vec![(6, 7), (10, 7), (7, 6), (8, 6), (9, 6), (7, 8), (8, 8), (9, 8)])]
+#[case((7, 7), Orientations::y(), "AT", vec![(7, 6), (7, 9), (6, 7), (8, 7), (6, 8), (8, 8)])]
+#[case((0, 0), Orientations::x(), "CAT", vec![(3, 0), (0, 1), (1, 1), (2, 1)])]
+#[case((12, 14), Orientations::x(), "CAT", vec![(11, 14), (12, 13), (13, 13), (14, 13)])]
+fn anchors_placed_word(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+    #[case] expected: Vec<(i32, i32)>,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement(start, orientation, word)))
+        .unwrap();
+
+    // when
+    let result = anchors(&board);
+
+    // then
+    assert_eq!(
+        result,
+        expected
+            .into_iter()
+            .map(Location::from)
+            .collect::<HashSet<_>>()
+    );
+}
+
+#[rstest]
+#[case(Orientations::y(), (8, 7), "STX")]
+#[case(Orientations::y(), (6, 7), "T")]
+#[case(Orientations::y(), (6, 8), "A")]
+#[case(Orientations::y(), (8, 8), "A")]
+#[case(Orientations::y(), (7, 6), "ABCDEFGHIJKLMNOPQRSTUVWXYZ")]
+#[case(Orientations::x(), (7, 6), "C")]
+#[case(Orientations::x(), (7, 9), "")]
+#[case(Orientations::x(), (8, 7), "ABCDEFGHIJKLMNOPQRSTUVWXYZ")]
+fn cross_checks_column(
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] anchor: (i32, i32),
+    #[case] expected: &str,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::y(), "AT")))
+        .unwrap();
+    let dictionary = HashSetDictionary::from_words(["at", "as", "ta", "cat", "ax"]);
+    let alphabet = Alphabets::english();
+
+    // when
+    let result = cross_checks(&board, &dictionary, alphabet.as_ref(), orientation.as_ref());
+
+    // then
+    assert_eq!(result.len(), 6);
+    assert_eq!(
+        result[&Location::from(anchor)],
+        expected.chars().collect::<HashSet<_>>()
+    );
+}
+
+#[rstest]
+#[case((7, 7), Orientations::x(), "CAT", true)]
+#[case((5, 7), Orientations::x(), "CAT", true)]
+#[case((7, 5), Orientations::y(), "CAT", true)]
+#[case((4, 7), Orientations::x(), "CAT", false)]
+#[case((8, 7), Orientations::x(), "CAT", false)]
+#[case((7, 5), Orientations::x(), "CAT", false)]
+fn first_move_covers_start_empty_board(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
     #[case] expected: bool,
 ) {
+    // given
+    let board = TestBoard::new(Dimension::of((15, 15)));
+    let placement = new_placement(start, orientation, word);
+
     // when
-    let result = lhs.eq(&rhs);
+    let result = first_move_covers_start(&board, &placement);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn first_move_covers_start_later_move() {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+    let placement = new_placement((0, 0), Orientations::x(), "DOG");
+
+    // when
+    let result = first_move_covers_start(&board, &placement);
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case((10, 7), Orientations::x(), "S", true)]
+#[case((4, 7), Orientations::x(), "BOB", true)]
+#[case((8, 8), Orientations::y(), "TO", true)]
+#[case((9, 4), Orientations::y(), "ATE", true)]
+#[case((7, 7), Orientations::x(), "DOG", true)]
+#[case((11, 7), Orientations::x(), "DOG", false)]
+#[case((6, 8), Orientations::x(), "X", false)]
+#[case((0, 0), Orientations::y(), "DOG", false)]
+fn is_connected_placement(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+    #[case] expected: bool,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+    let placement = new_placement(start, orientation, word);
+
+    // when
+    let result = is_connected(&board, &placement);
 
     // then
     assert_eq!(result, expected);
 }
 
+#[test]
+fn is_connected_empty_board() {
+    // given
+    let board = TestBoard::new(Dimension::of((15, 15)));
+    let placement = new_placement((0, 0), Orientations::x(), "DOG");
+
+    // when
+    let result = is_connected(&board, &placement);
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case((8, 6), Orientations::y(), "MAN")]
+#[case((7, 7), Orientations::x(), "CAT")]
+#[case((7, 7), Orientations::x(), "CATS")]
+#[case((7, 7), Orientations::x(), "cat")]
+#[case((9, 5), Orientations::y(), "OAT")]
+#[case((9, 5), Orientations::y(), "oaT")]
+#[case((0, 0), Orientations::x(), "DOG")]
+fn validate_overlap_reuse(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+    let placement = new_placement(start, orientation, word);
+
+    // when
+    let result = validate_overlap(&board, &placement);
+
+    // then
+    assert!(result.is_ok());
+}
+
+#[rstest]
+#[case((8, 6), Orientations::y(), "MEN")]
+#[case((7, 7), Orientations::x(), "COT")]
+#[case((7, 7), Orientations::x(), "cot")]
+#[case((5, 7), Orientations::x(), "DOG")]
+fn validate_overlap_conflict(
+    #[case] start: (i32, i32),
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] word: &str,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((15, 15)));
+    board
+        .place(Box::new(new_placement((7, 7), Orientations::x(), "CAT")))
+        .unwrap();
+    let placement = new_placement(start, orientation, word);
+
+    // when
+    let result = validate_overlap(&board, &placement);
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidPlacement)));
+}
+
+#[rstest]
+#[case(vec!['A', 'T'], vec!["at", "ta", "a"], vec![((0, 1), Orientations::x(), "AT"), ((0, 1), Orientations::x(), "TA"), ((1, 1), Orientations::x(), "AT"), ((1, 1), Orientations::x(), "TA"), ((1, 0), Orientations::y(), "AT"), ((1, 0), Orientations::y(), "TA"), ((1, 1), Orientations::y(), "AT"), ((1, 1), Orientations::y(), "TA")])]
+#[case(vec!['A', 'A'], vec!["aa"], vec![((0, 1), Orientations::x(), "AA"), ((1, 1), Orientations::x(), "AA"), ((1, 0), Orientations::y(), "AA"), ((1, 1), Orientations::y(), "AA")])]
+#[case(vec!['A', 'T'], vec!["cat"], vec![])]
+fn generate_moves_empty_board(
+    #[case] rack: Vec<char>,
+    #[case] words: Vec<&str>,
+    #[case] expected: Vec<TestMove>,
+) {
+    // given
+    let board = TestBoard::new(Dimension::of((3, 3)));
+    let rack: Vec<Box<dyn Piece>> = rack
+        .into_iter()
+        .map(|c| new_piece(Option::Some(c), 1, false) as Box<dyn Piece>)
+        .collect();
+    let dictionary = HashSetDictionary::from_words(words);
+
+    // when
+    let result = generate_moves(&board, &rack, &dictionary, Alphabets::english().as_ref());
+
+    // then
+    assert_eq!(result.len(), expected.len());
+    assert_eq!(moves(&result), moves_of(expected));
+}
+
+#[rstest]
+#[case(vec![Option::Some('C'), Option::Some('S')], vec!["at", "cat", "as"], vec![((0, 2), Orientations::x(), "C"), ((1, 3), Orientations::y(), "S")])]
+#[case(vec![Option::None], vec!["at", "cat"], vec![((0, 2), Orientations::x(), "C"), ((2, 1), Orientations::y(), "A"), ((1, 3), Orientations::y(), "T")])]
+fn generate_moves_extends_word(
+    #[case] rack: Vec<Option<char>>,
+    #[case] words: Vec<&str>,
+    #[case] expected: Vec<TestMove>,
+) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((5, 5)));
+    board
+        .place(Box::new(new_placement((1, 2), Orientations::x(), "AT")))
+        .unwrap();
+    let rack: Vec<Box<dyn Piece>> = rack
+        .into_iter()
+        .map(|c| new_piece(c, 1, c.is_none()) as Box<dyn Piece>)
+        .collect();
+    let dictionary = HashSetDictionary::from_words(words);
+
+    // when
+    let result = generate_moves(&board, &rack, &dictionary, Alphabets::english().as_ref());
+
+    // then
+    assert_eq!(result.len(), expected.len());
+    assert_eq!(moves(&result), moves_of(expected));
+    assert!(result.iter().all(|p| board.valid(p.as_ref())));
+}
+
+#[rstest]
+#[case(((1, 3), Orientations::x(), "TA"), true)]
+#[case(((1, 3), Orientations::x(), "AT"), false)]
+fn generate_moves_checks_cross_words(#[case] play: TestMove, #[case] expected: bool) {
+    // given
+    let mut board = TestBoard::new(Dimension::of((5, 5)));
+    board
+        .place(Box::new(new_placement((1, 2), Orientations::x(), "AT")))
+        .unwrap();
+    let rack: Vec<Box<dyn Piece>> = vec![
+        new_piece(Option::Some('T'), 1, false),
+        new_piece(Option::Some('A'), 1, false),
+    ];
+    let dictionary = HashSetDictionary::from_words(["at", "ta"]);
+
+    // when
+    let result = generate_moves(&board, &rack, &dictionary, Alphabets::english().as_ref());
+
+    // then
+    assert_eq!(moves(&result).is_superset(&moves_of(vec![play])), expected);
+}
+
+#[test]
+fn generate_moves_large_board() {
+    // given
+    let board = new_board(Dimension::of((4096, 4096)));
+    let rack: Vec<Box<dyn Piece>> = vec![
+        new_piece(Option::Some('A'), 1, false),
+        new_piece(Option::Some('T'), 1, false),
+    ];
+    let dictionary = HashSetDictionary::from_words(["at"]);
+
+    // when
+    let result = generate_moves(&board, &rack, &dictionary, Alphabets::english().as_ref());
+
+    // then
+    assert_eq!(
+        moves(&result),
+        moves_of(vec![
+            ((2047, 2048), Orientations::x(), "AT"),
+            ((2048, 2048), Orientations::x(), "AT"),
+            ((2048, 2047), Orientations::y(), "AT"),
+            ((2048, 2048), Orientations::y(), "AT"),
+        ])
+    );
+}
+
+/// Stands in for the Spanish "CH" digraph, which is a single letter sorted between "C" and "D".
+const DIGRAPH_CH: char = 'Ç';
+
+#[derive(Debug)]
+struct SequenceCollation {
+    sequence: Vec<char>,
+}
+impl Collation for SequenceCollation {
+    fn order(&self, a: &dyn Letter, b: &dyn Letter) -> Ordering {
+        let position = |l: &dyn Letter| self.sequence.iter().position(|&c| c == l.character());
+        position(a).cmp(&position(b))
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 struct TestLetter {
     character: char,
@@ -123,3 +1666,308 @@ fn new_piece(character: Option<char>, value: i32, wild: bool) -> Box<TestPiece>
         wild,
     })
 }
+
+/// A tile identified solely by its location; the piece and attributes on it do not affect
+/// equality, ordering, or hashing.
+#[derive(Clone, Debug)]
+struct TestTile {
+    location: Location,
+    piece: Option<Box<dyn Piece>>,
+    attributes: HashSet<Box<dyn TileAttribute>>,
+}
+impl TestTile {
+    fn new(location: Location) -> TestTile {
+        TestTile {
+            location,
+            piece: Option::None,
+            attributes: HashSet::new(),
+        }
+    }
+}
+impl Tile for TestTile {
+    fn location(&self) -> &Location {
+        &self.location
+    }
+
+    fn set_piece(&mut self, piece: Box<dyn Piece>) {
+        self.piece = Option::Some(piece);
+    }
+
+    fn piece(&self) -> Option<&dyn Piece> {
+        self.piece.as_deref()
+    }
+
+    fn base_value(&self) -> i32 {
+        self.piece.as_ref().map_or(0, |p| p.value())
+    }
+
+    fn add_attribute(&mut self, attribute: Box<dyn TileAttribute>) {
+        self.attributes.insert(attribute);
+    }
+
+    fn remove_attribute(&mut self, attribute: &dyn TileAttribute) {
+        self.attributes.retain(|a| a.as_ref() != attribute);
+    }
+
+    fn attributes(&self) -> &HashSet<Box<dyn TileAttribute>> {
+        &self.attributes
+    }
+}
+impl Eq for TestTile {}
+impl PartialEq for TestTile {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+    }
+}
+impl PartialOrd for TestTile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.location.cmp(&other.location))
+    }
+}
+impl Hash for TestTile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.location.hash(state)
+    }
+}
+
+/// A tile set that returns every attribute it holds from [`TileSet::attributes`] regardless of
+/// the locations requested.
+#[derive(Clone, Debug, Default)]
+struct TestTileSet {
+    tiles: HashMap<Location, TestTile>,
+    occupied: HashSet<Box<dyn Tile>>,
+    attributes: HashMap<Location, Vec<Box<dyn TileAttribute>>>,
+}
+impl TestTileSet {
+    fn set_piece(&mut self, location: &Location, piece: Box<dyn Piece>) {
+        let tile = self
+            .tiles
+            .entry(*location)
+            .or_insert_with(|| TestTile::new(*location));
+        tile.set_piece(piece);
+        self.occupied.replace(Box::new(tile.clone()));
+    }
+
+    fn remove_piece(&mut self, location: &Location) {
+        if let Some(tile) = self.tiles.get_mut(location) {
+            tile.piece = Option::None;
+            self.occupied
+                .remove(&(Box::new(tile.clone()) as Box<dyn Tile>));
+        }
+    }
+
+    fn add_attribute(&mut self, location: &Location, attribute: Box<dyn TileAttribute>) {
+        self.tiles
+            .entry(*location)
+            .or_insert_with(|| TestTile::new(*location))
+            .add_attribute(attribute.clone());
+        self.attributes
+            .entry(*location)
+            .or_default()
+            .push(attribute);
+    }
+}
+impl TileSet for TestTileSet {
+    fn clear(&mut self) {
+        self.tiles.clear();
+        self.occupied.clear();
+        self.attributes.clear();
+    }
+
+    fn tile(&mut self, location: &Location) -> &dyn Tile {
+        self.tiles
+            .entry(*location)
+            .or_insert_with(|| TestTile::new(*location))
+    }
+
+    fn tiles_in_line(&mut self, line: &Line) -> Vec<&dyn Tile> {
+        for location in line {
+            self.tiles
+                .entry(*location)
+                .or_insert_with(|| TestTile::new(*location));
+        }
+
+        line.iter()
+            .map(|location| &self.tiles[location] as &dyn Tile)
+            .collect()
+    }
+
+    fn compact(&mut self) {
+        self.tiles
+            .retain(|_, t| t.piece.is_some() || !t.attributes.is_empty());
+    }
+
+    fn occupied_tiles(&self) -> &HashSet<Box<dyn Tile>> {
+        &self.occupied
+    }
+
+    fn attributes(
+        &self,
+        _locations: &HashSet<Location>,
+    ) -> &HashMap<Location, Vec<Box<dyn TileAttribute>>> {
+        &self.attributes
+    }
+}
+
+/// A board that accepts any placement within its dimension, along one of its orientations, that
+/// does not overlap an existing piece. Points are calculated by [`score_placement`].
+#[derive(Clone, Debug)]
+struct TestBoard {
+    dimension: Dimension,
+    start: Location,
+    orientations: IndexSet<Box<dyn Orientation>>,
+    tiles: TestTileSet,
+    placements: Vec<Box<dyn Placement>>,
+}
+impl TestBoard {
+    fn new(dimension: Dimension) -> TestBoard {
+        TestBoard {
+            dimension,
+            start: Location::at((
+                dimension.width() as i32 / 2,
+                dimension.height() as i32 / 2,
+                dimension.depth() as i32 / 2,
+            )),
+            orientations: Orientations::xy(),
+            tiles: TestTileSet::default(),
+            placements: Vec::new(),
+        }
+    }
+}
+impl Board for TestBoard {
+    fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+
+    fn valid(&self, placement: &dyn Placement) -> bool {
+        self.orientations
+            .iter()
+            .any(|o| o.as_ref() == placement.orientation())
+            && placement.locations().iter().all(|l| {
+                self.dimension.contains(l)
+                    && self.tiles.tiles.get(l).is_none_or(|t| t.piece.is_none())
+            })
+    }
+
+    fn calculate_points(&self, placement: &dyn Placement) -> i32 {
+        score_placement(self, placement)
+    }
+
+    fn place(&mut self, placement: Box<dyn Placement>) -> std::result::Result<i32, Error> {
+        if !self.valid(placement.as_ref()) {
+            return Err(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: String::from("Placement is not valid on this board"),
+            });
+        }
+
+        let points = self.calculate_points(placement.as_ref());
+        for (location, piece) in placement.locations().iter().zip(placement.pieces()) {
+            self.tiles
+                .set_piece(location, Box::new(LockedPiece::new(piece.clone())));
+        }
+        self.placements.push(placement);
+
+        Ok(points)
+    }
+
+    fn remove_placement(&mut self, placement: &dyn Placement) -> std::result::Result<(), Error> {
+        let index = self
+            .placements
+            .iter()
+            .position(|p| p.as_ref() == placement)
+            .filter(|_| {
+                placement
+                    .locations()
+                    .iter()
+                    .zip(placement.pieces())
+                    .all(|(l, piece)| {
+                        self.tiles
+                            .tiles
+                            .get(l)
+                            .and_then(|t| t.piece())
+                            .is_some_and(|p| p.eq_exact(piece.as_ref()))
+                    })
+            })
+            .ok_or(Error {
+                kind: ErrorKind::InvalidPlacement,
+                message: String::from("Placement is not on this board"),
+            })?;
+
+        for location in placement.locations() {
+            self.tiles.remove_piece(&location);
+        }
+        self.placements.remove(index);
+
+        Ok(())
+    }
+
+    fn tiles(&self) -> &dyn TileSet {
+        &self.tiles
+    }
+
+    fn start(&self) -> &Location {
+        &self.start
+    }
+
+    fn orientations(&self) -> &IndexSet<Box<dyn Orientation>> {
+        &self.orientations
+    }
+}
+
+/// Create a [`BoardImpl`] of the given dimension with its start at the center and the xy
+/// orientations.
+fn new_board(dimension: Dimension) -> BoardImpl {
+    BoardImpl::new(
+        dimension,
+        Location::at((
+            dimension.width() as i32 / 2,
+            dimension.height() as i32 / 2,
+            dimension.depth() as i32 / 2,
+        )),
+        Orientations::xy(),
+    )
+    .unwrap()
+}
+
+/// A move expected from [`generate_moves`], given by its start location, orientation, and word.
+type TestMove = ((i32, i32), Box<dyn Orientation>, &'static str);
+
+/// Describe each of the given placements by its start location, orientation, and word.
+fn moves(placements: &[Box<dyn Placement>]) -> HashSet<(Location, Vector, String)> {
+    placements
+        .iter()
+        .map(|p| (*p.start_location(), p.orientation().vector(), p.word()))
+        .collect()
+}
+
+/// Describe each of the given moves as [`moves`] does.
+fn moves_of(moves: Vec<TestMove>) -> HashSet<(Location, Vector, String)> {
+    moves
+        .into_iter()
+        .map(|(start, orientation, word)| {
+            (
+                Location::from(start),
+                orientation.vector(),
+                word.to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Create a placement spelling the given word, where 'C' is worth 3 and every other letter 1.
+fn new_placement(
+    start: (i32, i32),
+    orientation: Box<dyn Orientation>,
+    word: &str,
+) -> PlacementImpl {
+    PlacementImpl::new(
+        Location::from(start),
+        orientation,
+        word.chars()
+            .map(|c| {
+                new_piece(Option::Some(c), if c == 'C' { 3 } else { 1 }, false) as Box<dyn Piece>
+            })
+            .collect(),
+    )
+}
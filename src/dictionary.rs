@@ -0,0 +1,159 @@
+/*
+ * Copyright © 2024 Gregory P. Moyer
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    io::{self, BufRead},
+};
+
+use dyn_clone::{clone_trait_object, DynClone};
+
+/// A dictionary is the set of words that are allowed to be played. Lookups ignore case.
+pub trait Dictionary: Debug + DynClone + Send + Sync {
+    /// Determine whether or not the given word is in this dictionary.
+    fn contains(&self, word: &str) -> bool;
+
+    /// Determine whether or not any word in this dictionary starts with the given prefix. A word
+    /// is a prefix of itself, and the empty prefix matches any non-empty dictionary.
+    fn is_prefix(&self, prefix: &str) -> bool;
+}
+
+clone_trait_object!(Dictionary);
+
+/// A [`Dictionary`] backed by hash sets of every word and every prefix of those words.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HashSetDictionary {
+    words: HashSet<String>,
+    prefixes: HashSet<String>,
+}
+
+impl HashSetDictionary {
+    /// Create a dictionary containing the given words.
+    pub fn from_words<I, S>(words: I) -> HashSetDictionary
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = HashSetDictionary::default();
+        for word in words {
+            dictionary.insert(word.as_ref());
+        }
+
+        dictionary
+    }
+
+    /// Create a dictionary from newline-delimited words, which are lowercased like any others.
+    /// Surrounding whitespace is trimmed, and blank lines and comment lines starting with `#` are
+    /// skipped. Any error reading from the given reader is returned.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<HashSetDictionary> {
+        Ok(HashSetDictionary::from_words(read_words(reader)?))
+    }
+
+    fn insert(&mut self, word: &str) {
+        let word = word.to_lowercase();
+        for (i, _) in word.char_indices() {
+            self.prefixes.insert(word[..i].to_string());
+        }
+        self.prefixes.insert(word.clone());
+        self.words.insert(word);
+    }
+}
+
+impl Dictionary for HashSetDictionary {
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    fn is_prefix(&self, prefix: &str) -> bool {
+        self.prefixes.contains(&prefix.to_lowercase())
+    }
+}
+
+/// A [`Dictionary`] backed by a trie, so that both lookups and prefix queries take time
+/// proportional to the length of the query rather than the size of the dictionary.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrieDictionary {
+    root: TrieNode,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    terminal: bool,
+}
+
+impl TrieDictionary {
+    /// Create a dictionary containing the given words.
+    pub fn from_words<I, S>(words: I) -> TrieDictionary
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = TrieDictionary::default();
+        for word in words {
+            dictionary.insert(word.as_ref());
+        }
+
+        dictionary
+    }
+
+    /// Create a dictionary from newline-delimited words, which are lowercased like any others.
+    /// Surrounding whitespace is trimmed, and blank lines and comment lines starting with `#` are
+    /// skipped. Any error reading from the given reader is returned.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<TrieDictionary> {
+        Ok(TrieDictionary::from_words(read_words(reader)?))
+    }
+
+    fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.to_lowercase().chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.terminal = true;
+    }
+
+    fn find(&self, query: &str) -> Option<&TrieNode> {
+        query
+            .to_lowercase()
+            .chars()
+            .try_fold(&self.root, |node, c| node.children.get(&c))
+    }
+}
+
+impl Dictionary for TrieDictionary {
+    fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.terminal)
+    }
+
+    fn is_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix)
+            .is_some_and(|node| node.terminal || !node.children.is_empty())
+    }
+}
+
+fn read_words<R: BufRead>(reader: R) -> io::Result<Vec<String>> {
+    let mut words = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let word = line.trim();
+        if !word.is_empty() && !word.starts_with('#') {
+            words.push(word.to_string());
+        }
+    }
+
+    Ok(words)
+}
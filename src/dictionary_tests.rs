@@ -0,0 +1,290 @@
+/*
+ * Copyright © 2024 Gregory P. Moyer
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::io::{self, BufReader, Cursor, Read};
+
+use rstest::rstest;
+
+use crate::dictionary::{Dictionary, HashSetDictionary, TrieDictionary};
+
+const WORDS: [&str; 6] = ["cat", "cats", "catalog", "dog", "Zebra", "ÉCLAIR"];
+
+#[rstest]
+#[case("cat", true)]
+#[case("cats", true)]
+#[case("catalog", true)]
+#[case("dog", true)]
+#[case("cata", false)]
+#[case("dogs", false)]
+#[case("bird", false)]
+#[case("", false)]
+fn hash_set_dictionary_contains(#[case] word: &str, #[case] expected: bool) {
+    // given
+    let dictionary = HashSetDictionary::from_words(WORDS);
+
+    // when
+    let result = dictionary.contains(word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case("CAT")]
+#[case("Cats")]
+#[case("zebra")]
+#[case("ZEBRA")]
+#[case("éclair")]
+fn hash_set_dictionary_contains_ignores_case(#[case] word: &str) {
+    // given
+    let dictionary = HashSetDictionary::from_words(WORDS);
+
+    // when
+    let result = dictionary.contains(word);
+
+    // then
+    assert!(result);
+}
+
+#[rstest]
+#[case("", true)]
+#[case("c", true)]
+#[case("ca", true)]
+#[case("cata", true)]
+#[case("catalog", true)]
+#[case("CATAL", true)]
+#[case("zeb", true)]
+#[case("écl", true)]
+#[case("catalogs", false)]
+#[case("cb", false)]
+#[case("x", false)]
+fn hash_set_dictionary_is_prefix(#[case] prefix: &str, #[case] expected: bool) {
+    // given
+    let dictionary = HashSetDictionary::from_words(WORDS);
+
+    // when
+    let result = dictionary.is_prefix(prefix);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn hash_set_dictionary_empty() {
+    // given
+    let dictionary = HashSetDictionary::from_words(Vec::<String>::new());
+
+    // when
+    let result = dictionary.is_prefix("");
+
+    // then
+    assert!(!result);
+    assert!(!dictionary.contains(""));
+}
+
+#[test]
+fn hash_set_dictionary_prune() {
+    // given
+    let dictionary = HashSetDictionary::from_words(WORDS);
+    let letters = ['a', 'c', 'g', 'l', 'o', 't'];
+
+    // when
+    let mut found = Vec::new();
+    let mut stack = vec![String::new()];
+    while let Some(prefix) = stack.pop() {
+        if dictionary.contains(&prefix) {
+            found.push(prefix.clone());
+        }
+        for letter in letters {
+            let next = format!("{prefix}{letter}");
+            if dictionary.is_prefix(&next) {
+                stack.push(next);
+            }
+        }
+    }
+    found.sort();
+
+    // then
+    assert_eq!(found, vec!["cat", "catalog"]);
+}
+
+#[rstest]
+#[case("cat", true)]
+#[case("CATS", true)]
+#[case("catalog", true)]
+#[case("zebra", true)]
+#[case("éclair", true)]
+#[case("cata", false)]
+#[case("dogs", false)]
+#[case("", false)]
+fn trie_dictionary_contains(#[case] word: &str, #[case] expected: bool) {
+    // given
+    let dictionary = TrieDictionary::from_words(WORDS);
+
+    // when
+    let result = dictionary.contains(word);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case("", true)]
+#[case("c", true)]
+#[case("cata", true)]
+#[case("CATAL", true)]
+#[case("écl", true)]
+#[case("catalogs", false)]
+#[case("x", false)]
+fn trie_dictionary_is_prefix(#[case] prefix: &str, #[case] expected: bool) {
+    // given
+    let dictionary = TrieDictionary::from_words(WORDS);
+
+    // when
+    let result = dictionary.is_prefix(prefix);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn trie_dictionary_empty() {
+    // given
+    let dictionary = TrieDictionary::from_words(Vec::<String>::new());
+
+    // when
+    let result = dictionary.is_prefix("");
+
+    // then
+    assert!(!result);
+    assert!(!dictionary.contains(""));
+}
+
+#[test]
+fn trie_dictionary_matches_hash_set() {
+    // given
+    let words = generate_words("abcde", 4, 3);
+    let trie = TrieDictionary::from_words(&words);
+    let reference = HashSetDictionary::from_words(&words);
+
+    // when
+    let queries = generate_words("abcdef", 5, 1);
+
+    // then
+    for query in queries {
+        assert_eq!(trie.contains(&query), reference.contains(&query), "{query}");
+        assert_eq!(
+            trie.is_prefix(&query),
+            reference.is_prefix(&query),
+            "{query}"
+        );
+    }
+}
+
+#[test]
+fn trie_dictionary_prefix_queries() {
+    // given
+    let words = generate_words("abcdefgh", 5, 2);
+    let dictionary = TrieDictionary::from_words(&words);
+    let queries = generate_words("abcdefghi", 5, 1);
+
+    // when
+    let result = queries.iter().filter(|q| dictionary.is_prefix(q)).count();
+
+    // then
+    let reference = HashSetDictionary::from_words(&words);
+    let expected = queries.iter().filter(|q| reference.is_prefix(q)).count();
+    assert_eq!(result, expected);
+    assert!(result > 0 && result < queries.len());
+}
+
+const WORD_FILE: &str = "# animals\nCat\n  DOG  \n\n  # striped\nzebra\r\n\tÉclair\n\n   \n#\n";
+
+#[test]
+fn hash_set_dictionary_from_reader() {
+    // when
+    let result = HashSetDictionary::from_reader(Cursor::new(WORD_FILE)).unwrap();
+
+    // then
+    assert_eq!(
+        result,
+        HashSetDictionary::from_words(["cat", "dog", "zebra", "éclair"])
+    );
+    assert!(!result.contains(""));
+}
+
+#[test]
+fn trie_dictionary_from_reader() {
+    // when
+    let result = TrieDictionary::from_reader(Cursor::new(WORD_FILE)).unwrap();
+
+    // then
+    assert_eq!(
+        result,
+        TrieDictionary::from_words(["cat", "dog", "zebra", "éclair"])
+    );
+    assert!(!result.contains(""));
+}
+
+#[rstest]
+#[case("#cat\n", false)]
+#[case("  # cat\n", false)]
+#[case("cat#\n", true)]
+fn dictionary_from_reader_comments(#[case] text: &str, #[case] expected: bool) {
+    // when
+    let hash_set = HashSetDictionary::from_reader(Cursor::new(text)).unwrap();
+    let trie = TrieDictionary::from_reader(Cursor::new(text)).unwrap();
+
+    // then
+    assert_eq!(hash_set.contains("cat#"), expected);
+    assert_eq!(trie.contains("cat#"), expected);
+    assert!(!hash_set.contains("cat"));
+    assert!(!trie.contains("cat"));
+}
+
+#[test]
+fn dictionary_from_reader_error() {
+    // when
+    let hash_set = HashSetDictionary::from_reader(BufReader::new(FailingReader));
+    let trie = TrieDictionary::from_reader(BufReader::new(FailingReader));
+
+    // then
+    assert!(matches!(hash_set, Err(e) if e.kind() == io::ErrorKind::BrokenPipe));
+    assert!(matches!(trie, Err(e) if e.kind() == io::ErrorKind::BrokenPipe));
+}
+
+struct FailingReader;
+impl Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+/// Generate every string of the given letters with a length of no more than the given maximum,
+/// keeping only every `stride`-th string so that the set has gaps.
+fn generate_words(letters: &str, max_length: usize, stride: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut frontier = vec![String::new()];
+    for _ in 0..max_length {
+        frontier = frontier
+            .iter()
+            .flat_map(|prefix| letters.chars().map(move |c| format!("{prefix}{c}")))
+            .collect();
+        words.extend(frontier.iter().cloned());
+    }
+
+    words.into_iter().skip(1).step_by(stride).collect()
+}
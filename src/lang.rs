@@ -14,16 +14,33 @@
  * limitations under the License.
  */
 
-use std::{fmt::{Debug, Display}, hash::{Hash, Hasher}};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display, Formatter, Result},
+    hash::{Hash, Hasher},
+};
 
 use dyn_clone::{clone_trait_object, DynClone};
 
 use crate::rust::{DynEq, DynHash};
 
 /// A letter represents a single character that, when put together with other letters, creates a word that can be used in a placement.
-pub trait Letter: Debug + Display + DynClone + DynEq + DynHash {
+pub trait Letter: Debug + Display + DynClone + DynEq + DynHash + Send + Sync {
     /// Retrieve the character that represents this letter.
     fn character(&self) -> char;
+
+    /// Determine whether or not this letter can be played in the language of the given
+    /// [`Alphabet`], meaning that the alphabet has a letter with the same character, ignoring case.
+    fn is_playable(&self, alphabet: &dyn Alphabet) -> bool {
+        let character = self.character();
+        alphabet.letters().iter().any(|letter| {
+            letter
+                .character()
+                .to_lowercase()
+                .eq(character.to_lowercase())
+        })
+    }
 }
 
 clone_trait_object!(Letter);
@@ -41,3 +58,181 @@ impl PartialEq<dyn Letter> for dyn Letter {
         self.as_dyn_eq() == other.as_dyn_eq()
     }
 }
+
+/// A letter identified only by its character.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LetterImpl {
+    character: char,
+}
+
+impl LetterImpl {
+    pub fn new(character: char) -> LetterImpl {
+        LetterImpl { character }
+    }
+}
+
+impl Letter for LetterImpl {
+    fn character(&self) -> char {
+        self.character
+    }
+}
+
+impl Display for LetterImpl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.character)
+    }
+}
+
+/// A collation defines the order in which [`Letter`] instances are sorted for display, allowing a
+/// language to place letters differently than their character ordering would (e.g. a digraph
+/// such as "CH" sorting after "C").
+pub trait Collation: Debug + Send + Sync {
+    /// Compare the given letters according to this collation.
+    fn order(&self, a: &dyn Letter, b: &dyn Letter) -> Ordering;
+}
+
+/// The default collation, which orders letters by their character.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AsciiCollation;
+
+impl Collation for AsciiCollation {
+    fn order(&self, a: &dyn Letter, b: &dyn Letter) -> Ordering {
+        a.character().cmp(&b.character())
+    }
+}
+
+/// Letter values define the base number of points each [`Letter`] is worth, such as when
+/// determining the value of a piece.
+pub trait LetterValues: Debug + Send + Sync {
+    /// Retrieve the base value of the given letter.
+    fn value(&self, letter: &dyn Letter) -> i32;
+}
+
+/// Letter values keyed by the character of each [`Letter`]. Letters without a value are worth 0.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CharacterValues {
+    values: HashMap<char, i32>,
+}
+
+impl CharacterValues {
+    /// Create letter values from pairs of a character and the value of the [`Letter`] having that
+    /// character.
+    pub fn new<I: IntoIterator<Item = (char, i32)>>(values: I) -> CharacterValues {
+        CharacterValues {
+            values: values.into_iter().collect(),
+        }
+    }
+}
+
+impl LetterValues for CharacterValues {
+    fn value(&self, letter: &dyn Letter) -> i32 {
+        self.values.get(&letter.character()).copied().unwrap_or(0)
+    }
+}
+
+/// An alphabet describes the [`Letter`] instances available in a language along with how many
+/// tiles of each exist. The base value of each letter comes from [`LetterValues`]. A blank tile,
+/// which has no letter, is represented by nothing and is worth 0.
+pub trait Alphabet: LetterValues {
+    /// Retrieve every letter in this alphabet, not including blanks.
+    fn letters(&self) -> Vec<Box<dyn Letter>>;
+
+    /// Retrieve how many tiles of the given letter (or blank) exist.
+    fn count(&self, letter: Option<&dyn Letter>) -> usize;
+
+    /// Retrieve the total number of tiles, including blanks.
+    fn tile_count(&self) -> usize {
+        self.letters()
+            .iter()
+            .map(|letter| self.count(Some(&**letter)))
+            .sum::<usize>()
+            + self.count(None)
+    }
+}
+
+/// Provides the built-in [`Alphabet`] implementations.
+pub struct Alphabets;
+impl Alphabets {
+    /// Retrieve the English alphabet with the standard distribution of 100 tiles, including 2
+    /// blanks worth 0.
+    pub fn english() -> Box<dyn Alphabet> {
+        Box::new(CharacterAlphabet::new(
+            [
+                ('A', 9, 1),
+                ('B', 2, 3),
+                ('C', 2, 3),
+                ('D', 4, 2),
+                ('E', 12, 1),
+                ('F', 2, 4),
+                ('G', 3, 2),
+                ('H', 2, 4),
+                ('I', 9, 1),
+                ('J', 1, 8),
+                ('K', 1, 5),
+                ('L', 4, 1),
+                ('M', 2, 3),
+                ('N', 6, 1),
+                ('O', 8, 1),
+                ('P', 2, 3),
+                ('Q', 1, 10),
+                ('R', 6, 1),
+                ('S', 4, 1),
+                ('T', 6, 1),
+                ('U', 4, 1),
+                ('V', 2, 4),
+                ('W', 2, 4),
+                ('X', 1, 8),
+                ('Y', 2, 4),
+                ('Z', 1, 10),
+            ],
+            2,
+        ))
+    }
+}
+
+/// An alphabet keyed by character, holding the count of each letter and their values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CharacterAlphabet {
+    counts: BTreeMap<char, usize>,
+    values: CharacterValues,
+    blanks: usize,
+}
+
+impl CharacterAlphabet {
+    /// Create an alphabet from the character, count, and value of each letter and the number of
+    /// blanks.
+    fn new<I: IntoIterator<Item = (char, usize, i32)>>(letters: I, blanks: usize) -> Self {
+        let (counts, values): (BTreeMap<char, usize>, Vec<(char, i32)>) = letters
+            .into_iter()
+            .map(|(character, count, value)| ((character, count), (character, value)))
+            .unzip();
+
+        CharacterAlphabet {
+            counts,
+            values: CharacterValues::new(values),
+            blanks,
+        }
+    }
+}
+
+impl LetterValues for CharacterAlphabet {
+    fn value(&self, letter: &dyn Letter) -> i32 {
+        self.values.value(letter)
+    }
+}
+
+impl Alphabet for CharacterAlphabet {
+    fn letters(&self) -> Vec<Box<dyn Letter>> {
+        self.counts
+            .keys()
+            .map(|&c| Box::new(LetterImpl::new(c)) as Box<dyn Letter>)
+            .collect()
+    }
+
+    fn count(&self, letter: Option<&dyn Letter>) -> usize {
+        match letter {
+            Some(letter) => self.counts.get(&letter.character()).copied().unwrap_or(0),
+            None => self.blanks,
+        }
+    }
+}
@@ -0,0 +1,245 @@
+/*
+ * Copyright © 2024 Gregory P. Moyer
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result},
+    thread,
+};
+
+use rstest::rstest;
+
+use crate::lang::{
+    Alphabets, AsciiCollation, CharacterValues, Collation, Letter, LetterImpl, LetterValues,
+};
+
+#[rstest]
+#[case('A', 'A', Ordering::Equal)]
+#[case('A', 'B', Ordering::Less)]
+#[case('B', 'A', Ordering::Greater)]
+fn ascii_collation_order(#[case] lhs: char, #[case] rhs: char, #[case] expected: Ordering) {
+    // given
+    let collation = AsciiCollation;
+
+    // when
+    let result = collation.order(&*new_letter(lhs), &*new_letter(rhs));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn custom_collation_digraph() {
+    // given
+    let collation = SequenceCollation {
+        sequence: vec!['B', 'C', DIGRAPH_CH, 'D'],
+    };
+    let mut letters = [
+        new_letter('D'),
+        new_letter(DIGRAPH_CH),
+        new_letter('C'),
+        new_letter('B'),
+    ];
+
+    // when
+    letters.sort_by(|a, b| collation.order(&**a, &**b));
+
+    // then
+    let result: Vec<char> = letters.iter().map(|l| l.character()).collect();
+    assert_eq!(result, vec!['B', 'C', DIGRAPH_CH, 'D']);
+}
+
+#[test]
+fn collation_send() {
+    // given
+    let collation: Box<dyn Collation> = Box::new(SequenceCollation {
+        sequence: vec!['B', 'A'],
+    });
+
+    // when
+    let result = thread::spawn(move || collation.order(&*new_letter('A'), &*new_letter('B')))
+        .join()
+        .unwrap();
+
+    // then
+    assert_eq!(result, Ordering::Greater);
+}
+
+#[test]
+fn ascii_collation_digraph() {
+    // given
+    let mut letters = [
+        new_letter('D'),
+        new_letter(DIGRAPH_CH),
+        new_letter('C'),
+        new_letter('B'),
+    ];
+
+    // when
+    letters.sort_by(|a, b| AsciiCollation.order(&**a, &**b));
+
+    // then
+    let result: Vec<char> = letters.iter().map(|l| l.character()).collect();
+    assert_eq!(result, vec!['B', 'C', 'D', DIGRAPH_CH]);
+}
+
+#[rstest]
+#[case('A', 1)]
+#[case('K', 5)]
+#[case('Q', 10)]
+#[case(DIGRAPH_CH, 5)]
+#[case('B', 0)]
+fn character_values_value(#[case] character: char, #[case] expected: i32) {
+    // given
+    let values = CharacterValues::new([('A', 1), ('K', 5), ('Q', 10), (DIGRAPH_CH, 5)]);
+
+    // when
+    let result = values.value(&*new_letter(character));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn character_values_word() {
+    // given
+    let values: Box<dyn LetterValues> =
+        Box::new(CharacterValues::new([('A', 1), ('K', 5), ('Q', 10)]));
+    let word = [new_letter('Q'), new_letter('A'), new_letter('K')];
+
+    // when
+    let result: i32 = word.iter().map(|l| values.value(&**l)).sum();
+
+    // then
+    assert_eq!(result, 16);
+}
+
+#[rstest]
+#[case('A', true)]
+#[case('z', true)]
+#[case('Ñ', false)]
+#[case('1', false)]
+fn letter_is_playable(#[case] character: char, #[case] expected: bool) {
+    // given
+    let alphabet = Alphabets::english();
+
+    // when
+    let result = new_letter(character).is_playable(alphabet.as_ref());
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn letter_impl() {
+    // when
+    let result = LetterImpl::new('A');
+
+    // then
+    assert_eq!(result.character(), 'A');
+    assert_eq!(result.to_string(), "A");
+}
+
+#[test]
+fn alphabets_english() {
+    // given
+    let alphabet = Alphabets::english();
+
+    // when
+    let result = alphabet.tile_count();
+
+    // then
+    assert_eq!(result, 100);
+    assert_eq!(alphabet.count(None), 2);
+    assert_eq!(alphabet.letters().len(), 26);
+}
+
+#[rstest]
+#[case('A', 9, 1)]
+#[case('E', 12, 1)]
+#[case('K', 1, 5)]
+#[case('Q', 1, 10)]
+#[case('Z', 1, 10)]
+#[case('Ñ', 0, 0)]
+fn alphabets_english_letter(
+    #[case] character: char,
+    #[case] expected_count: usize,
+    #[case] expected_value: i32,
+) {
+    // given
+    let alphabet = Alphabets::english();
+    let letter = LetterImpl::new(character);
+
+    // when
+    let count = alphabet.count(Some(&letter));
+    let value = alphabet.value(&letter);
+
+    // then
+    assert_eq!(count, expected_count);
+    assert_eq!(value, expected_value);
+}
+
+#[test]
+fn alphabets_english_letter_values() {
+    // given
+    let alphabet = Alphabets::english();
+    let values: &dyn LetterValues = alphabet.as_ref();
+    let word = [
+        new_letter('Q'),
+        new_letter('U'),
+        new_letter('I'),
+        new_letter('Z'),
+    ];
+
+    // when
+    let result: i32 = word.iter().map(|l| values.value(&**l)).sum();
+
+    // then
+    assert_eq!(result, 22);
+}
+
+/// Stands in for the Spanish "CH" digraph, which is a single letter sorted between "C" and "D".
+const DIGRAPH_CH: char = 'Ç';
+
+#[derive(Debug)]
+struct SequenceCollation {
+    sequence: Vec<char>,
+}
+impl Collation for SequenceCollation {
+    fn order(&self, a: &dyn Letter, b: &dyn Letter) -> Ordering {
+        let position = |l: &dyn Letter| self.sequence.iter().position(|&c| c == l.character());
+        position(a).cmp(&position(b))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct TestLetter {
+    character: char,
+}
+impl Letter for TestLetter {
+    fn character(&self) -> char {
+        self.character
+    }
+}
+impl Display for TestLetter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.character)
+    }
+}
+
+fn new_letter(character: char) -> Box<dyn Letter> {
+    Box::new(TestLetter { character })
+}
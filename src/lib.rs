@@ -13,7 +13,15 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
+//! Core abstractions for building word games.
+//!
+//! The component, language, and spatial traits ([`component::Board`], [`component::Piece`],
+//! [`lang::Letter`], [`space::Orientation`], etc.) all require `Send + Sync` so that game state
+//! can be moved between and shared across threads, such as when running simulations in parallel.
+//! Implementors must therefore avoid non-thread-safe interior state like `Rc` or `RefCell`.
+
 pub mod component;
+pub mod dictionary;
 pub mod lang;
 pub mod space;
 
@@ -22,5 +30,11 @@ mod rust;
 #[cfg(test)]
 mod component_tests;
 
+#[cfg(test)]
+mod dictionary_tests;
+
+#[cfg(test)]
+mod lang_tests;
+
 #[cfg(test)]
 mod space_tests;
@@ -15,59 +15,155 @@
  */
 
 use std::cmp;
-use std::collections::BTreeSet;
-use std::fmt::Debug;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::num::TryFromIntError;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::{slice, vec};
 
 use dyn_clone::{clone_trait_object, DynClone};
 use indexmap::{indexset, IndexSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::rust::{DynEq, DynHash};
 
+#[derive(Debug)]
+pub enum ErrorKind {
+    InvalidDimension,
+    SearchLimitExceeded,
+}
+
+/// An error from constructing a spatial value such as a [`Dimension`].
+#[derive(Debug)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
 /// Defines a container in two- or three-dimensional space.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DimensionFields"))]
 pub struct Dimension {
     width: u32,
     height: u32,
     depth: u32,
 }
 
+/// The unvalidated form of a [`Dimension`] read during deserialization. It is checked with
+/// [`Dimension::try_of`], so deserialized dimensions are also limited to
+/// [`Dimension::MAX_VOLUME`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DimensionFields {
+    width: u32,
+    height: u32,
+    depth: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DimensionFields> for Dimension {
+    type Error = Error;
+
+    fn try_from(value: DimensionFields) -> Result<Dimension, Error> {
+        Dimension::try_of((value.width, value.height, value.depth))
+    }
+}
+
 pub trait DimensionOf {
     fn of(self) -> Dimension;
+
+    /// Create a dimension, returning an error if it is not valid or if its volume exceeds the
+    /// given maximum. By default this creates the dimension with [`DimensionOf::of`], which may
+    /// panic, and then checks its volume; implementations should override it to validate their
+    /// arguments without panicking.
+    fn try_of(self, max_volume: u64) -> Result<Dimension, Error>
+    where
+        Self: Sized,
+    {
+        let dimension = self.of();
+        (dimension.width, dimension.height, dimension.depth).try_of(max_volume)
+    }
 }
 
 impl DimensionOf for (u32, u32) {
     fn of(self) -> Dimension {
         Dimension::of((self.0, self.1, 1))
     }
+
+    fn try_of(self, max_volume: u64) -> Result<Dimension, Error> {
+        (self.0, self.1, 1).try_of(max_volume)
+    }
 }
 
 impl DimensionOf for (u32, u32, u32) {
     fn of(self) -> Dimension {
+        match self.try_of(u64::MAX) {
+            Ok(dimension) => dimension,
+            Err(e) => panic!("{}", e.message),
+        }
+    }
+
+    fn try_of(self, max_volume: u64) -> Result<Dimension, Error> {
+        let invalid = |message: &str| {
+            Err(Error {
+                kind: ErrorKind::InvalidDimension,
+                message: String::from(message),
+            })
+        };
+
         if self.0 < 1 {
-            panic!("Dimension width must be positive");
+            return invalid("Dimension width must be positive");
         }
         if self.1 < 1 {
-            panic!("Dimension height must be positive");
+            return invalid("Dimension height must be positive");
         }
         if self.2 < 1 {
-            panic!("Dimension depth must be positive");
+            return invalid("Dimension depth must be positive");
+        }
+
+        let volume = (self.0 as u64)
+            .checked_mul(self.1 as u64)
+            .and_then(|v| v.checked_mul(self.2 as u64));
+        if volume.is_none_or(|v| v > max_volume) {
+            return invalid("Dimension volume is too large");
         }
 
-        Dimension {
+        Ok(Dimension {
             width: self.0,
             height: self.1,
             depth: self.2,
-        }
+        })
     }
 }
 
 impl Dimension {
+    /// The largest volume accepted by [`Dimension::try_of`].
+    pub const MAX_VOLUME: u64 = 1 << 24;
+
     pub fn of<A: DimensionOf>(args: A) -> Dimension {
         args.of()
     }
 
+    /// Create a dimension, returning an error rather than panicking if any size is not positive
+    /// or if the volume exceeds [`Dimension::MAX_VOLUME`].
+    pub fn try_of<A: DimensionOf>(args: A) -> Result<Dimension, Error> {
+        args.try_of(Dimension::MAX_VOLUME)
+    }
+
+    /// Create a dimension as with [`Dimension::try_of`], but with a caller-supplied maximum volume.
+    pub fn try_of_with_limit<A: DimensionOf>(args: A, max_volume: u64) -> Result<Dimension, Error> {
+        args.try_of(max_volume)
+    }
+
     pub fn width(&self) -> u32 {
         self.width
     }
@@ -80,6 +176,11 @@ impl Dimension {
         self.depth
     }
 
+    /// Calculate the number of locations within this dimension.
+    pub fn volume(&self) -> u64 {
+        self.width as u64 * self.height as u64 * self.depth as u64
+    }
+
     pub fn contains(&self, location: &Location) -> bool {
         let test_x: Result<u32, TryFromIntError> = location.x().try_into();
         if test_x.is_err() {
@@ -102,10 +203,181 @@ impl Dimension {
 
         x < self.width && y < self.height && z < self.depth
     }
+
+    /// Determine whether or not every location of the given [`Line`] falls within this dimension.
+    pub fn contains_line(&self, line: &Line) -> bool {
+        line.iter().all(|location| self.contains(location))
+    }
+
+    /// Retrieve the nearest [`Location`] within this dimension to the given location by clamping
+    /// each axis into range. Locations already within this dimension are returned unchanged.
+    pub fn clamp_location(&self, location: &Location) -> Location {
+        let clamp = |coordinate: i32, size: u32| {
+            let max = cmp::min(size.saturating_sub(1), i32::MAX as u32) as i32;
+            coordinate.clamp(0, max)
+        };
+
+        Location::at((
+            clamp(location.x(), self.width),
+            clamp(location.y(), self.height),
+            clamp(location.z(), self.depth),
+        ))
+    }
+
+    /// Iterate over every [`Location`] within this dimension, with x changing fastest, then y, and
+    /// then z.
+    pub fn locations(&self) -> impl Iterator<Item = Location> {
+        let width = self.width as i32;
+        let height = self.height as i32;
+        let depth = self.depth as i32;
+
+        (0..depth).flat_map(move |z| {
+            (0..height).flat_map(move |y| (0..width).map(move |x| Location::at((x, y, z))))
+        })
+    }
+
+    /// Iterate over every [`Location`] within this dimension in boustrophedon (snake) order: row by
+    /// row along the y-axis and then layer by layer along the z-axis, with each row traversed along
+    /// the x-axis in the opposite direction from the row before it.
+    pub fn snake_locations(&self) -> impl Iterator<Item = Location> {
+        let width = self.width as i32;
+        let height = self.height as i32;
+        let depth = self.depth as i32;
+
+        (0..depth)
+            .flat_map(move |z| (0..height).map(move |y| (y, z)))
+            .enumerate()
+            .flat_map(move |(row, (y, z))| {
+                (0..width).map(move |i| {
+                    let x = if row % 2 == 0 { i } else { width - 1 - i };
+                    Location::at((x, y, z))
+                })
+            })
+    }
+
+    /// Calculate the fewest steps from the given [`Location`] to any edge of this dimension, where
+    /// 0 means the location is on an edge. Axes of size 1 (such as the depth of a flat board) are
+    /// not considered edges. Locations outside this dimension yield a negative result.
+    pub fn edge_distance(&self, location: &Location) -> i32 {
+        [
+            (location.x(), self.width),
+            (location.y(), self.height),
+            (location.z(), self.depth),
+        ]
+        .into_iter()
+        .filter(|&(_, size)| size > 1)
+        .map(|(coordinate, size)| {
+            let coordinate = coordinate as i64;
+            cmp::min(coordinate, size as i64 - 1 - coordinate)
+        })
+        .min()
+        .map_or(0, |d| d.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    /// Determine whether or not every given [`Location`] falls within this dimension, stopping at
+    /// the first one that does not.
+    pub fn contains_all(&self, locations: &[Location]) -> bool {
+        let width = self.width as i64;
+        let height = self.height as i64;
+        let depth = self.depth as i64;
+
+        locations.iter().all(|location| {
+            let x = location.x() as i64;
+            let y = location.y() as i64;
+            let z = location.z() as i64;
+
+            x >= 0 && x < width && y >= 0 && y < height && z >= 0 && z < depth
+        })
+    }
+}
+
+/// Tracks which [`Location`] instances within a [`Dimension`] are occupied using a single bit per
+/// location. This is far more compact than a set of locations for densely filled boards. Locations
+/// outside the dimension are never occupied.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OccupancyBitmap {
+    dimension: Dimension,
+    bits: Vec<u64>,
+}
+
+impl OccupancyBitmap {
+    /// Create an empty bitmap covering every location in the given dimension.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidDimension`], before allocating anything, if the
+    /// volume of the dimension exceeds [`Dimension::MAX_VOLUME`].
+    pub fn new(dimension: &Dimension) -> Result<OccupancyBitmap, Error> {
+        let dimension = Dimension::try_of((dimension.width, dimension.height, dimension.depth))?;
+
+        Ok(OccupancyBitmap {
+            dimension,
+            bits: vec![0; dimension.volume().div_ceil(64) as usize],
+        })
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        &self.dimension
+    }
+
+    /// Mark the given [`Location`] as occupied. Returns true if the location was previously
+    /// unoccupied and false if it was already occupied or is outside the dimension.
+    pub fn set(&mut self, location: &Location) -> bool {
+        match self.position(location) {
+            Some((word, mask)) if self.bits[word] & mask == 0 => {
+                self.bits[word] |= mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Mark the given [`Location`] as unoccupied. Returns true if the location was previously
+    /// occupied.
+    pub fn clear(&mut self, location: &Location) -> bool {
+        match self.position(location) {
+            Some((word, mask)) if self.bits[word] & mask != 0 => {
+                self.bits[word] &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Determine whether or not the given [`Location`] is occupied.
+    pub fn get(&self, location: &Location) -> bool {
+        self.position(location)
+            .is_some_and(|(word, mask)| self.bits[word] & mask != 0)
+    }
+
+    /// Count the occupied locations.
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn position(&self, location: &Location) -> Option<(usize, u64)> {
+        if !self.dimension.contains(location) {
+            return None;
+        }
+
+        let width = self.dimension.width() as u64;
+        let height = self.dimension.height() as u64;
+        let index = location.x() as u64
+            + location.y() as u64 * width
+            + location.z() as u64 * width * height;
+
+        Some(((index / 64) as usize, 1 << (index % 64)))
+    }
 }
 
 /// Defines the absolute separation between two [`Location`] instances.
+///
+/// The derived ordering is lexicographic by x, then y, then z; it does not reflect magnitude. Use
+/// [`Distance::total_cmp_by_magnitude`], [`Distance::by_chebyshev`], or
+/// [`Distance::by_manhattan`] to order distances by length.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Distance {
     x: i32,
     y: i32,
@@ -139,6 +411,18 @@ impl DistanceOf for (i32, i32, i32) {
     }
 }
 
+impl From<(i32, i32)> for Distance {
+    fn from(value: (i32, i32)) -> Distance {
+        Distance::of(value)
+    }
+}
+
+impl From<(i32, i32, i32)> for Distance {
+    fn from(value: (i32, i32, i32)) -> Distance {
+        Distance::of(value)
+    }
+}
+
 impl Distance {
     pub fn zero() -> &'static Distance {
         &ZERO
@@ -172,62 +456,256 @@ impl Distance {
         ))
     }
 
+    /// Determine whether or not this distance fits within the given bounding distance. The
+    /// comparison is component-wise and inclusive: each axis of this distance must be less than or
+    /// equal to the same axis of the bound.
     pub fn is_within(&self, distance: &Distance) -> bool {
         self.x <= distance.x() && self.y <= distance.y() && self.z <= distance.z()
     }
+
+    /// Calculate the Chebyshev length of this distance, which is the largest single component.
+    pub fn chebyshev(&self) -> i32 {
+        cmp::max(self.x, cmp::max(self.y, self.z))
+    }
+
+    /// Calculate the Manhattan length of this distance, which is the sum of all components. The
+    /// result saturates at [`i32::MAX`].
+    pub fn manhattan(&self) -> i32 {
+        self.x.saturating_add(self.y).saturating_add(self.z)
+    }
+
+    /// Determine whether or not each component of this distance differs from the corresponding
+    /// component of the given distance by no more than the given tolerance.
+    pub fn approx_eq(&self, other: &Distance, tolerance: i32) -> bool {
+        (self.x - other.x).abs() <= tolerance
+            && (self.y - other.y).abs() <= tolerance
+            && (self.z - other.z).abs() <= tolerance
+    }
+
+    /// Wrap this distance so that it is ordered by its [`Distance::chebyshev`] length.
+    pub fn by_chebyshev(self) -> ByChebyshev {
+        ByChebyshev(self)
+    }
+
+    /// Wrap this distance so that it is ordered by its [`Distance::manhattan`] length.
+    pub fn by_manhattan(self) -> ByManhattan {
+        ByManhattan(self)
+    }
+
+    /// Calculate the square of the Euclidean length of this distance. Comparing squared lengths
+    /// orders distances by straight-line magnitude without resorting to floating point.
+    ///
+    /// The result saturates at [`i64::MAX`] for distances approaching [`Distance::max`].
+    pub fn squared_length(&self) -> i64 {
+        let x = self.x as i64;
+        let y = self.y as i64;
+        let z = self.z as i64;
+
+        (x * x).saturating_add(y * y).saturating_add(z * z)
+    }
+
+    /// Compare this distance to the given distance by magnitude: first by
+    /// [`Distance::chebyshev`] length, then by [`Distance::manhattan`] length, and finally by the
+    /// default lexicographic ordering so that only equal distances compare as equal.
+    pub fn total_cmp_by_magnitude(&self, other: &Distance) -> cmp::Ordering {
+        self.chebyshev()
+            .cmp(&other.chebyshev())
+            .then_with(|| self.manhattan().cmp(&other.manhattan()))
+            .then_with(|| self.cmp(other))
+    }
+}
+
+/// Orders a [`Distance`] by its Chebyshev length rather than the default lexicographic ordering.
+/// Distances of equal length fall back to the default ordering so that this remains consistent
+/// with equality.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ByChebyshev(pub Distance);
+
+impl Ord for ByChebyshev {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0
+            .chebyshev()
+            .cmp(&other.0.chebyshev())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for ByChebyshev {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-/// Defines a path between start and end [`Location`] instances.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// Orders a [`Distance`] by its Manhattan length rather than the default lexicographic ordering.
+/// Distances of equal length fall back to the default ordering so that this remains consistent
+/// with equality.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ByManhattan(pub Distance);
+
+impl Ord for ByManhattan {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0
+            .manhattan()
+            .cmp(&other.0.manhattan())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for ByManhattan {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Defines a path between start and end [`Location`] instances. The order in which the path was
+/// walked from its original start is retained for [`Line::start`], [`Line::end`], and iteration,
+/// but lines are compared and hashed by the locations they cover alone, so lines between the same
+/// endpoints in opposite directions are equal.
+#[derive(Clone, Debug)]
 pub struct Line {
     locations: BTreeSet<Location>,
+    path: Vec<Location>,
+}
+
+impl Eq for Line {}
+
+impl PartialEq for Line {
+    fn eq(&self, other: &Self) -> bool {
+        self.locations == other.locations
+    }
+}
+
+impl Hash for Line {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.locations.hash(state)
+    }
+}
+
+impl Ord for Line {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.locations.cmp(&other.locations)
+    }
+}
+
+impl PartialOrd for Line {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Line {
+    /// Create a line from start to end with exactly one location per step along the axis of
+    /// greatest change. The other axes are rounded to the nearest location at each step (halves
+    /// round away from the start) using integer arithmetic only, so long lines do not drift.
     pub fn between(start: &Location, end: &Location) -> Line {
-        let mut locations: BTreeSet<Location> = BTreeSet::new();
-        locations.insert(*start);
-
-        let d = Distance::between(&start, &end);
-        let n: f32 = cmp::max(d.x(), cmp::max(d.y(), d.z())) as f32;
-
-        let sx: f32 = d.x() as f32 / n;
-        let sy: f32 = d.y() as f32 / n;
-        let sz: f32 = d.z() as f32 / n;
-
-        let mut px: f32 = start.x() as f32;
-        let mut py: f32 = start.y() as f32;
-        let mut pz: f32 = start.z() as f32;
-        for _ in 0..(n as i32) {
-            px += sx;
-            py += sy;
-            pz += sz;
-
-            locations.insert(Location::at((
-                px.round() as i32,
-                py.round() as i32,
-                pz.round() as i32,
+        let mut path: Vec<Location> = vec![*start];
+
+        let v = Vector::from(start, end);
+        let d: [i64; 3] = [v.x() as i64, v.y() as i64, v.z() as i64];
+        let n: i64 = d.iter().map(|c| c.abs()).max().unwrap();
+
+        for k in 1..=n {
+            // round(k * d / n) with halves away from zero, computed as floor((2k|d| + n) / 2n)
+            let offset = |c: i64| (c.signum() * ((2 * k * c.abs() + n) / (2 * n))) as i32;
+
+            path.push(Location::at((
+                start.x() + offset(d[0]),
+                start.y() + offset(d[1]),
+                start.z() + offset(d[2]),
             )));
         }
 
-        Line { locations }
+        Line::from_path(path)
+    }
+
+    /// Create a line that moves along exactly one axis per step (a supercover-style
+    /// rasterization), guaranteeing that each location on the path is adjacent to the one before
+    /// it.
+    pub fn between_connected(start: &Location, end: &Location) -> Line {
+        let mut path: Vec<Location> = vec![*start];
+
+        let v = Vector::from(start, end);
+        let n: [i64; 3] = [v.x().abs() as i64, v.y().abs() as i64, v.z().abs() as i64];
+        let step: [i32; 3] = [v.x().signum(), v.y().signum(), v.z().signum()];
+
+        let mut i: [i64; 3] = [0, 0, 0];
+        let mut p: [i32; 3] = [start.x(), start.y(), start.z()];
+
+        // Step along whichever axis crosses its next cell boundary first, comparing the
+        // fractions (1 + 2i) / 2n exactly by cross-multiplying.
+        while let Some(axis) = (0..3)
+            .filter(|&a| i[a] < n[a])
+            .min_by(|&a, &b| ((1 + 2 * i[a]) * n[b]).cmp(&((1 + 2 * i[b]) * n[a])))
+        {
+            i[axis] += 1;
+            p[axis] += step[axis];
+
+            path.push(Location::at((p[0], p[1], p[2])));
+        }
+
+        Line::from_path(path)
     }
 
+    fn from_path(path: Vec<Location>) -> Line {
+        Line {
+            locations: path.iter().copied().collect(),
+            path,
+        }
+    }
+
+    /// Retrieve the location the line was walked from, which is the first location of
+    /// [`Line::iter`].
     pub fn start(&self) -> &Location {
-        self.locations.first().unwrap()
+        self.path.first().unwrap()
     }
 
+    /// Retrieve the location the line was walked to, which is the last location of
+    /// [`Line::iter`].
     pub fn end(&self) -> &Location {
-        self.locations.last().unwrap()
+        self.path.last().unwrap()
     }
 
     pub fn contains(&self, location: &Location) -> bool {
         self.locations.contains(location)
     }
+
+    /// Retrieve the locations of this line in the order they are walked from the original start
+    /// endpoint, regardless of the direction the line travels.
+    pub fn ordered_locations(&self) -> Vec<Location> {
+        self.path.clone()
+    }
+
+    /// Iterate over the locations of this line in the order they are walked, from the original
+    /// start endpoint to the original end endpoint.
+    pub fn iter(&self) -> slice::Iter<'_, Location> {
+        self.path.iter()
+    }
+}
+
+impl IntoIterator for Line {
+    type Item = Location;
+    type IntoIter = vec::IntoIter<Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.path.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Line {
+    type Item = &'a Location;
+    type IntoIter = slice::Iter<'a, Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 /// Defines a location in space without concern for what may or may not be at that location.
+///
+/// The derived ordering is lexicographic by x, then y, then z. Note that the inherent
+/// [`Location::min`] and [`Location::max`] are component-wise instead.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Location {
     x: i32,
     y: i32,
@@ -254,6 +732,18 @@ impl LocationAt for (i32, i32, i32) {
     }
 }
 
+impl From<(i32, i32)> for Location {
+    fn from(value: (i32, i32)) -> Location {
+        Location::at(value)
+    }
+}
+
+impl From<(i32, i32, i32)> for Location {
+    fn from(value: (i32, i32, i32)) -> Location {
+        Location::at(value)
+    }
+}
+
 impl Location {
     pub fn at<A: LocationAt>(args: A) -> Location {
         args.at()
@@ -288,15 +778,155 @@ impl Location {
             && (self.y - other_y).abs() <= distance.y()
             && (self.z - other_z).abs() <= distance.z()
     }
+
+    /// Calculate the total number of single-axis steps between this and the given location, which
+    /// is the [`Distance::manhattan`] length of the distance between them.
+    pub fn manhattan_distance(&self, other: &Location) -> i32 {
+        Distance::between(self, other).manhattan()
+    }
+
+    /// Retrieve the locations one step backward and then one step forward along each of the given
+    /// orientations, in the order the orientations are given.
+    pub fn neighbors(&self, orientations: &[Box<dyn Orientation>]) -> Vec<Location> {
+        orientations
+            .iter()
+            .flat_map(|orientation| [orientation.go(self, -1), orientation.go(self, 1)])
+            .collect()
+    }
+
+    /// Determine whether or not each component of this location differs from the corresponding
+    /// component of the given location by no more than the given tolerance. This is useful when
+    /// asserting on results that are sensitive to rounding.
+    pub fn approx_eq(&self, other: &Location, tolerance: i32) -> bool {
+        self.is_within(&Distance::of((tolerance, tolerance, tolerance)), other)
+    }
+
+    /// Retrieve the location made up of the smaller of each component of this and the given
+    /// location. Unlike the lexicographic [`Ord::min`], every component is compared.
+    pub fn component_min(&self, other: &Location) -> Location {
+        Location::at((
+            cmp::min(self.x, other.x),
+            cmp::min(self.y, other.y),
+            cmp::min(self.z, other.z),
+        ))
+    }
+
+    /// Retrieve the location made up of the larger of each component of this and the given
+    /// location. Unlike the lexicographic [`Ord::max`], every component is compared.
+    pub fn component_max(&self, other: &Location) -> Location {
+        Location::at((
+            cmp::max(self.x, other.x),
+            cmp::max(self.y, other.y),
+            cmp::max(self.z, other.z),
+        ))
+    }
+}
+
+/// Adding a [`Vector`] to a [`Location`] is equivalent to [`Location::go`].
+impl Add<Vector> for Location {
+    type Output = Location;
+
+    fn add(self, rhs: Vector) -> Location {
+        self.go(&rhs)
+    }
+}
+
+impl Add<&Vector> for &Location {
+    type Output = Location;
+
+    fn add(self, rhs: &Vector) -> Location {
+        self.go(rhs)
+    }
+}
+
+/// Subtracting the start [`Location`] from the end yields the same [`Vector`] as [`Vector::from`].
+impl Sub for Location {
+    type Output = Vector;
+
+    fn sub(self, rhs: Location) -> Vector {
+        Vector::from(&rhs, &self)
+    }
+}
+
+impl Sub<&Location> for &Location {
+    type Output = Vector;
+
+    fn sub(self, rhs: &Location) -> Vector {
+        *self - *rhs
+    }
+}
+
+/// Defines the smallest box that encloses a set of [`Location`] instances.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BoundingBox {
+    min: Location,
+    max: Location,
+}
+
+impl BoundingBox {
+    /// Create the box enclosing all of the given locations. Nothing is returned if no locations
+    /// are given.
+    pub fn from_locations(locations: &[Location]) -> Option<BoundingBox> {
+        let (first, rest) = locations.split_first()?;
+
+        Some(rest.iter().fold(
+            BoundingBox {
+                min: *first,
+                max: *first,
+            },
+            |bounds, location| BoundingBox {
+                min: bounds.min.component_min(location),
+                max: bounds.max.component_max(location),
+            },
+        ))
+    }
+
+    /// Retrieve the corner of this box with the smallest coordinates.
+    pub fn min(&self) -> &Location {
+        &self.min
+    }
+
+    /// Retrieve the corner of this box with the largest coordinates.
+    pub fn max(&self) -> &Location {
+        &self.max
+    }
+
+    /// Retrieve the [`Dimension`] that covers this box, inclusive of both corners.
+    pub fn dimension(&self) -> Dimension {
+        let size =
+            |min: i32, max: i32| u32::try_from(max as i64 - min as i64 + 1).unwrap_or(u32::MAX);
+
+        Dimension::of((
+            size(self.min.x(), self.max.x()),
+            size(self.min.y(), self.max.y()),
+            size(self.min.z(), self.max.z()),
+        ))
+    }
 }
 
 /// This interface represents a spatial orientation that defines an infinite length line along which any number of [`Location`] can exist.
-pub trait Orientation: DynClone + DynEq + DynHash + Debug {
+pub trait Orientation: DynClone + DynEq + DynHash + Debug + Send + Sync {
     /// Move from the given [`Location`] by the provided amount (negative or positive) along the line defined by this orientation.
     fn go(&self, location: &Location, amount: i32) -> Location;
 
     /// Determine if the given [`Distance`] exists entirely within the line defined by this orientation.
     fn contains(&self, distance: &Distance) -> bool;
+
+    /// Retrieve the unit step [`Vector`] along this orientation, which is the vector moved by [`Orientation::go`] with an amount of 1.
+    fn vector(&self) -> Vector {
+        let origin = Location::at((0, 0, 0));
+        Vector::from(&origin, &self.go(&origin, 1))
+    }
+
+    /// Retrieve the [`Vector`] that identifies this orientation. Any orientation can be recreated from this vector with [`<dyn Orientation>::from_vector`](trait.Orientation.html#method.from_vector). This is the same as [`Orientation::vector`].
+    fn to_vector(&self) -> Vector {
+        self.vector()
+    }
+
+    /// Retrieve the orientation that walks the same line in the opposite direction. A reversed orientation is not equal to the original even though both define the same line, while reversing twice yields an orientation equal to the original.
+    fn reverse(&self) -> Box<dyn Orientation> {
+        <dyn Orientation>::from_vector(&-self.vector())
+    }
 }
 
 clone_trait_object!(Orientation);
@@ -315,6 +945,98 @@ impl PartialEq<dyn Orientation> for dyn Orientation {
     }
 }
 
+impl dyn Orientation {
+    /// Create the orientation whose single step is exactly the given [`Vector`], so that its
+    /// [`Orientation::vector`] is that vector. A built-in orientation from [`Orientations`] is
+    /// returned when one matches, otherwise a [`CustomOrientation`]. Unlike
+    /// [`Orientations::axis_of`], which only classifies the axis of a vector, the direction and
+    /// length of the step are kept: (-3, 0, 0) is a custom orientation rather than
+    /// [`Orientations::x`].
+    pub fn from_vector(vector: &Vector) -> Box<dyn Orientation> {
+        [
+            Orientations::x(),
+            Orientations::y(),
+            Orientations::z(),
+            Orientations::xy_diagonal(),
+            Orientations::xyz_diagonal(),
+        ]
+        .into_iter()
+        .find(|o| o.vector() == *vector)
+        .unwrap_or_else(|| Box::new(CustomOrientation { vector: *vector }))
+    }
+}
+
+/// The most locations that [`orientation_distance`] will visit before giving up.
+pub const ORIENTATION_DISTANCE_LIMIT: usize = 1 << 18;
+
+/// Calculate the fewest steps needed to move from start to end when each step moves one
+/// [`Location`] forward or backward along one of the given orientations. Nothing is returned if
+/// end cannot be reached using only those orientations.
+///
+/// The search is confined to the box spanned by start and end, padded on every side by three
+/// times the largest component of any orientation's step (see [`Orientation::vector`]). That is
+/// enough room to reorder the steps of any shortest path so that it stays inside the box, so no
+/// shorter route is missed.
+///
+/// Returns an error of kind [`ErrorKind::SearchLimitExceeded`] if the search visits more than
+/// [`ORIENTATION_DISTANCE_LIMIT`] locations before finding end, which bounds the work done for
+/// locations that are far apart. In that case end may still be reachable.
+pub fn orientation_distance(
+    start: &Location,
+    end: &Location,
+    orientations: &[Box<dyn Orientation>],
+) -> Result<Option<i32>, Error> {
+    let padding = 3 * orientations
+        .iter()
+        .map(|o| {
+            let v = o.vector();
+            [v.x(), v.y(), v.z()]
+                .iter()
+                .map(|c| (*c as i64).abs())
+                .max()
+                .unwrap()
+        })
+        .max()
+        .unwrap_or(1);
+    let bounds = |a: i32, b: i32| (a.min(b) as i64 - padding)..=(a.max(b) as i64 + padding);
+    let (x, y, z) = (
+        bounds(start.x(), end.x()),
+        bounds(start.y(), end.y()),
+        bounds(start.z(), end.z()),
+    );
+    let in_bounds = |l: &Location| {
+        x.contains(&(l.x() as i64)) && y.contains(&(l.y() as i64)) && z.contains(&(l.z() as i64))
+    };
+
+    let mut visited: HashSet<Location> = HashSet::from([*start]);
+    let mut queue: VecDeque<(Location, i32)> = VecDeque::from([(*start, 0)]);
+    while let Some((location, steps)) = queue.pop_front() {
+        if location == *end {
+            return Ok(Some(steps));
+        }
+
+        for orientation in orientations {
+            for amount in [-1, 1] {
+                let next = orientation.go(&location, amount);
+                if in_bounds(&next) && visited.insert(next) {
+                    if visited.len() > ORIENTATION_DISTANCE_LIMIT {
+                        return Err(Error {
+                            kind: ErrorKind::SearchLimitExceeded,
+                            message: format!(
+                                "Gave up after visiting {} locations",
+                                ORIENTATION_DISTANCE_LIMIT
+                            ),
+                        });
+                    }
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
 pub struct Orientations;
 impl Orientations {
     pub fn x() -> Box<dyn Orientation> {
@@ -329,6 +1051,32 @@ impl Orientations {
         Box::new(ZOrientation {})
     }
 
+    /// Retrieve the orientation along the diagonal of the xy plane, stepping equally in x and y.
+    pub fn xy_diagonal() -> Box<dyn Orientation> {
+        Box::new(XyDiagonalOrientation {})
+    }
+
+    /// Retrieve the orientation along the body diagonal, stepping equally in x, y, and z.
+    pub fn xyz_diagonal() -> Box<dyn Orientation> {
+        Box::new(XyzDiagonalOrientation {})
+    }
+
+    /// Classify the given [`Vector`] as one of the axis orientations [`Orientations::x`],
+    /// [`Orientations::y`], or [`Orientations::z`] when it lies purely along that axis in either
+    /// direction and with any length. Nothing is returned for the zero vector or a vector that
+    /// moves along more than one axis. See
+    /// [`<dyn Orientation>::from_vector`](trait.Orientation.html#method.from_vector) to create the
+    /// orientation that steps by exactly the given vector instead.
+    pub fn axis_of(vector: &Vector) -> Option<Box<dyn Orientation>> {
+        match (vector.x(), vector.y(), vector.z()) {
+            (0, 0, 0) => None,
+            (_, 0, 0) => Some(Self::x()),
+            (0, _, 0) => Some(Self::y()),
+            (0, 0, _) => Some(Self::z()),
+            _ => None,
+        }
+    }
+
     pub fn xy() -> IndexSet<Box<dyn Orientation>> {
         indexset! {Self::x(), Self::y()}
     }
@@ -348,6 +1096,10 @@ impl Orientation for XOrientation {
     fn contains(&self, distance: &Distance) -> bool {
         distance.y() == 0 && distance.z() == 0
     }
+
+    fn vector(&self) -> Vector {
+        Vector::of((1, 0, 0))
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -360,6 +1112,10 @@ impl Orientation for YOrientation {
     fn contains(&self, distance: &Distance) -> bool {
         distance.x() == 0 && distance.z() == 0
     }
+
+    fn vector(&self) -> Vector {
+        Vector::of((0, 1, 0))
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -372,10 +1128,242 @@ impl Orientation for ZOrientation {
     fn contains(&self, distance: &Distance) -> bool {
         distance.x() == 0 && distance.y() == 0
     }
+
+    fn vector(&self) -> Vector {
+        Vector::of((0, 0, 1))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct XyDiagonalOrientation;
+impl Orientation for XyDiagonalOrientation {
+    fn go(&self, location: &Location, amount: i32) -> Location {
+        location.go(&Vector::of((amount, amount, 0)))
+    }
+
+    fn contains(&self, distance: &Distance) -> bool {
+        distance.x() != 0 && distance.x() == distance.y() && distance.z() == 0
+    }
+
+    fn vector(&self) -> Vector {
+        Vector::of((1, 1, 0))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct XyzDiagonalOrientation;
+impl Orientation for XyzDiagonalOrientation {
+    fn go(&self, location: &Location, amount: i32) -> Location {
+        location.go(&Vector::of((amount, amount, amount)))
+    }
+
+    fn contains(&self, distance: &Distance) -> bool {
+        distance.x() != 0 && distance.x() == distance.y() && distance.y() == distance.z()
+    }
+
+    fn vector(&self) -> Vector {
+        Vector::of((1, 1, 1))
+    }
+}
+
+/// Defines how the edges of a [`Dimension`] behave when a [`Location`] moves past them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Topology {
+    /// Locations beyond the edges do not exist.
+    #[default]
+    Bounded,
+
+    /// Each edge wraps around to the opposite edge, so stepping off one side reappears on the
+    /// other.
+    Toroidal,
+}
+
+impl Topology {
+    /// Map the given [`Location`] onto the given [`Dimension`] according to this topology. A
+    /// bounded topology yields nothing for locations outside the dimension, while a toroidal
+    /// topology wraps them back inside.
+    pub fn normalize(&self, dimension: &Dimension, location: &Location) -> Option<Location> {
+        match self {
+            Topology::Bounded => Some(*location).filter(|l| dimension.contains(l)),
+            Topology::Toroidal => Some(Location::at((
+                wrap(location.x(), dimension.width()),
+                wrap(location.y(), dimension.height()),
+                wrap(location.z(), dimension.depth()),
+            ))),
+        }
+    }
+
+    /// Calculate the [`Distance`] between two locations within the given [`Dimension`]. A
+    /// toroidal topology measures each axis the short way around, so opposite edges are adjacent.
+    pub fn distance(&self, dimension: &Dimension, start: &Location, end: &Location) -> Distance {
+        let distance = Distance::between(start, end);
+        match self {
+            Topology::Bounded => distance,
+            Topology::Toroidal => Distance::of((
+                wrapped_separation(distance.x(), dimension.width()),
+                wrapped_separation(distance.y(), dimension.height()),
+                wrapped_separation(distance.z(), dimension.depth()),
+            )),
+        }
+    }
+}
+
+fn wrap(value: i32, size: u32) -> i32 {
+    (value as i64).rem_euclid(size as i64) as i32
+}
+
+fn wrapped_separation(separation: i32, size: u32) -> i32 {
+    let separation = (separation as i64).rem_euclid(size as i64);
+    cmp::min(separation, size as i64 - separation) as i32
+}
+
+/// An orientation that steps by an arbitrary [`Vector`], created by
+/// [`<dyn Orientation>::from_vector`](trait.Orientation.html#method.from_vector) when no built-in
+/// orientation matches.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CustomOrientation {
+    vector: Vector,
+}
+
+impl Orientation for CustomOrientation {
+    fn go(&self, location: &Location, amount: i32) -> Location {
+        location.go(&(self.vector * amount))
+    }
+
+    fn contains(&self, distance: &Distance) -> bool {
+        let step = Distance::of((self.vector.x(), self.vector.y(), self.vector.z()));
+        let mut multiple: Option<i32> = None;
+        for (d, s) in [
+            (distance.x(), step.x()),
+            (distance.y(), step.y()),
+            (distance.z(), step.z()),
+        ] {
+            if s == 0 {
+                if d != 0 {
+                    return false;
+                }
+            } else if d % s != 0 || *multiple.get_or_insert(d / s) != d / s {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn vector(&self) -> Vector {
+        self.vector
+    }
+}
+
+/// Defines a uniform remapping of [`Location`] instances built from translations, axis swaps, and
+/// reflections. Transforms are combined with [`Transform::then`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Transform {
+    matrix: [[i32; 3]; 3],
+    translation: Vector,
+}
+
+impl Transform {
+    pub fn identity() -> Transform {
+        Transform {
+            matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+            translation: Vector::of((0, 0, 0)),
+        }
+    }
+
+    /// Move every location by the given [`Vector`].
+    pub fn translation(vector: &Vector) -> Transform {
+        Transform {
+            translation: *vector,
+            ..Transform::identity()
+        }
+    }
+
+    /// Exchange the x and y components of every location.
+    pub fn swap_xy() -> Transform {
+        Transform::linear([[0, 1, 0], [1, 0, 0], [0, 0, 1]])
+    }
+
+    /// Exchange the x and z components of every location.
+    pub fn swap_xz() -> Transform {
+        Transform::linear([[0, 0, 1], [0, 1, 0], [1, 0, 0]])
+    }
+
+    /// Exchange the y and z components of every location.
+    pub fn swap_yz() -> Transform {
+        Transform::linear([[1, 0, 0], [0, 0, 1], [0, 1, 0]])
+    }
+
+    /// Negate the x component of every location, reflecting across the plane x = 0.
+    pub fn reflect_x() -> Transform {
+        Transform::linear([[-1, 0, 0], [0, 1, 0], [0, 0, 1]])
+    }
+
+    /// Negate the y component of every location, reflecting across the plane y = 0.
+    pub fn reflect_y() -> Transform {
+        Transform::linear([[1, 0, 0], [0, -1, 0], [0, 0, 1]])
+    }
+
+    /// Negate the z component of every location, reflecting across the plane z = 0.
+    pub fn reflect_z() -> Transform {
+        Transform::linear([[1, 0, 0], [0, 1, 0], [0, 0, -1]])
+    }
+
+    fn linear(matrix: [[i32; 3]; 3]) -> Transform {
+        Transform {
+            matrix,
+            ..Transform::identity()
+        }
+    }
+
+    /// Map the given [`Location`] through this transform.
+    pub fn apply(&self, location: &Location) -> Location {
+        let [x, y, z] = self.rotate([location.x(), location.y(), location.z()]);
+        Location::at((
+            x + self.translation.x(),
+            y + self.translation.y(),
+            z + self.translation.z(),
+        ))
+    }
+
+    /// Combine this transform with the given transform, producing a single transform that applies
+    /// this one first and the given one second.
+    pub fn then(&self, next: &Transform) -> Transform {
+        let mut matrix = [[0; 3]; 3];
+        for (r, row) in matrix.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| next.matrix[r][k] * self.matrix[k][c]).sum();
+            }
+        }
+
+        let [x, y, z] = next.rotate([
+            self.translation.x(),
+            self.translation.y(),
+            self.translation.z(),
+        ]);
+
+        Transform {
+            matrix,
+            translation: Vector::of((
+                x + next.translation.x(),
+                y + next.translation.y(),
+                z + next.translation.z(),
+            )),
+        }
+    }
+
+    fn rotate(&self, v: [i32; 3]) -> [i32; 3] {
+        self.matrix
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    }
 }
 
 /// Defines the distance and direction to go from one [`Location`] to another.
+///
+/// The derived ordering is lexicographic by x, then y, then z; it does not reflect magnitude. Note
+/// that the inherent [`Vector::min`] and [`Vector::max`] are component-wise instead.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector {
     x: i32,
     y: i32,
@@ -402,6 +1390,18 @@ impl VectorOf for (i32, i32, i32) {
     }
 }
 
+impl From<(i32, i32)> for Vector {
+    fn from(value: (i32, i32)) -> Vector {
+        Vector::of(value)
+    }
+}
+
+impl From<(i32, i32, i32)> for Vector {
+    fn from(value: (i32, i32, i32)) -> Vector {
+        Vector::of(value)
+    }
+}
+
 impl Vector {
     pub fn of<A: VectorOf>(args: A) -> Vector {
         args.of()
@@ -419,6 +1419,8 @@ impl Vector {
         self.z
     }
 
+    /// Retrieve the vector that moves from the start [`Location`] to the end. This is equivalent to
+    /// `end - start`.
     pub fn from(start: &Location, end: &Location) -> Vector {
         Vector::of((
             end.x() - start.x(),
@@ -426,4 +1428,107 @@ impl Vector {
             end.z() - start.z(),
         ))
     }
+
+    /// Retrieve the vector made up of the smaller of each component of this and the given vector.
+    /// Unlike the lexicographic [`Ord::min`], every component is compared.
+    pub fn component_min(&self, other: &Vector) -> Vector {
+        Vector::of((
+            cmp::min(self.x, other.x),
+            cmp::min(self.y, other.y),
+            cmp::min(self.z, other.z),
+        ))
+    }
+
+    /// Retrieve the vector made up of the larger of each component of this and the given vector.
+    /// Unlike the lexicographic [`Ord::max`], every component is compared.
+    pub fn component_max(&self, other: &Vector) -> Vector {
+        Vector::of((
+            cmp::max(self.x, other.x),
+            cmp::max(self.y, other.y),
+            cmp::max(self.z, other.z),
+        ))
+    }
+
+    /// Retrieve the Euclidean length of this vector.
+    pub fn magnitude(&self) -> f64 {
+        let (x, y, z) = (self.x as f64, self.y as f64, self.z as f64);
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    /// Retrieve the sum of the absolute values of each component of this vector.
+    pub fn manhattan_length(&self) -> i32 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// Retrieve the dot product of this and the given vector. Perpendicular vectors have a dot
+    /// product of zero.
+    pub fn dot(&self, other: &Vector) -> i32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Retrieve the cross product of this and the given vector, which is perpendicular to both.
+    /// Parallel vectors have a cross product of zero.
+    pub fn cross(&self, other: &Vector) -> Vector {
+        Vector::of((
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        ))
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::of((self.x + rhs.x, self.y + rhs.y, self.z + rhs.z))
+    }
+}
+
+impl Add<&Vector> for &Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: &Vector) -> Vector {
+        *self + *rhs
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector::of((self.x - rhs.x, self.y - rhs.y, self.z - rhs.z))
+    }
+}
+
+impl Sub<&Vector> for &Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: &Vector) -> Vector {
+        *self - *rhs
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i32) -> Vector {
+        Vector::of((self.x * rhs, self.y * rhs, self.z * rhs))
+    }
+}
+
+impl Mul<Vector> for i32 {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Vector {
+        rhs * self
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::of((-self.x, -self.y, -self.z))
+    }
 }
@@ -14,15 +14,24 @@
  * limitations under the License.
  */
 
+use crate::space::orientation_distance;
+use crate::space::BoundingBox;
 use crate::space::Dimension;
+use crate::space::DimensionOf;
 use crate::space::Distance;
+use crate::space::ErrorKind;
 use crate::space::Line;
 use crate::space::Location;
+use crate::space::OccupancyBitmap;
 use crate::space::Orientation;
 use crate::space::Orientations;
+use crate::space::Topology;
+use crate::space::Transform;
 use crate::space::Vector;
 use rstest::rstest;
 use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::{Add, Sub};
 
 // Dimension start =====
 
@@ -67,6 +76,100 @@ fn dimension_illegal_values(#[case] width: u32, #[case] height: u32, #[case] dep
     Dimension::of((width, height, depth));
 }
 
+#[test]
+#[should_panic]
+fn dimension_of_overflowing_volume() {
+    // when
+    Dimension::of((u32::MAX, u32::MAX, u32::MAX));
+}
+
+#[rstest]
+#[case(15, 15, 1)]
+#[case(1, 1, 1)]
+#[case(256, 256, 256)]
+fn dimension_try_of(#[case] width: u32, #[case] height: u32, #[case] depth: u32) {
+    // when
+    let result = Dimension::try_of((width, height, depth)).unwrap();
+
+    // then
+    assert_eq!(result, Dimension::of((width, height, depth)));
+}
+
+#[test]
+fn dimension_try_of_width_height() {
+    // when
+    let result = Dimension::try_of((15, 15)).unwrap();
+
+    // then
+    assert_eq!(result, Dimension::of((15, 15, 1)));
+}
+
+#[rstest]
+#[case(0, 1, 1)]
+#[case(1, 0, 1)]
+#[case(1, 1, 0)]
+#[case(256, 256, 257)]
+#[case(u32::MAX, u32::MAX, u32::MAX)]
+#[case(u32::MAX, 1, 1)]
+fn dimension_try_of_invalid(#[case] width: u32, #[case] height: u32, #[case] depth: u32) {
+    // when
+    let result = Dimension::try_of((width, height, depth));
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidDimension)));
+}
+
+#[rstest]
+#[case(10, true)]
+#[case(9, false)]
+fn dimension_try_of_with_limit(#[case] max_volume: u64, #[case] expected: bool) {
+    // when
+    let result = Dimension::try_of_with_limit((5, 2), max_volume);
+
+    // then
+    assert_eq!(result.is_ok(), expected);
+}
+
+#[rstest]
+#[case(10, true)]
+#[case(8, false)]
+fn dimension_of_default_try_of(#[case] max_volume: u64, #[case] expected: bool) {
+    // given
+    struct Square(u32);
+    impl DimensionOf for Square {
+        fn of(self) -> Dimension {
+            Dimension::of((self.0, self.0))
+        }
+    }
+
+    // when
+    let result = Dimension::try_of_with_limit(Square(3), max_volume);
+
+    // then
+    assert_eq!(result.is_ok(), expected);
+}
+
+#[rstest]
+#[case(1, 1, 1, 1)]
+#[case(15, 15, 1, 225)]
+#[case(2, 3, 4, 24)]
+#[case(u32::MAX, 1, 1, u32::MAX as u64)]
+fn dimension_volume(
+    #[case] width: u32,
+    #[case] height: u32,
+    #[case] depth: u32,
+    #[case] expected: u64,
+) {
+    // given
+    let dimension = Dimension::of((width, height, depth));
+
+    // when
+    let result = dimension.volume();
+
+    // then
+    assert_eq!(result, expected);
+}
+
 #[rstest]
 #[case(0, 0, 0, true)]
 #[case(1, 0, 0, true)]
@@ -94,6 +197,209 @@ fn dimension_contains(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] ex
     assert_eq!(result, expected);
 }
 
+#[test]
+fn dimension_snake_locations() {
+    // given
+    let dimension = Dimension::of((3, 2, 1));
+
+    // when
+    let result: Vec<Location> = dimension.snake_locations().collect();
+
+    // then
+    assert_eq!(
+        result,
+        vec![
+            Location::at((0, 0, 0)),
+            Location::at((1, 0, 0)),
+            Location::at((2, 0, 0)),
+            Location::at((2, 1, 0)),
+            Location::at((1, 1, 0)),
+            Location::at((0, 1, 0)),
+        ]
+    );
+    assert_eq!(result.len() as u64, dimension.volume());
+}
+
+#[test]
+fn dimension_snake_locations_3d() {
+    // given
+    let dimension = Dimension::of((2, 1, 2));
+
+    // when
+    let result: Vec<Location> = dimension.snake_locations().collect();
+
+    // then
+    assert_eq!(
+        result,
+        vec![
+            Location::at((0, 0, 0)),
+            Location::at((1, 0, 0)),
+            Location::at((1, 0, 1)),
+            Location::at((0, 0, 1)),
+        ]
+    );
+}
+
+#[rstest]
+#[case(Location::at((7, 7, 0)), 7)]
+#[case(Location::at((0, 5, 0)), 0)]
+#[case(Location::at((14, 5, 0)), 0)]
+#[case(Location::at((5, 14, 0)), 0)]
+#[case(Location::at((0, 0, 0)), 0)]
+#[case(Location::at((14, 14, 0)), 0)]
+#[case(Location::at((3, 9, 0)), 3)]
+#[case(Location::at((-1, 7, 0)), -1)]
+fn dimension_edge_distance(#[case] location: Location, #[case] expected: i32) {
+    // given
+    let dimension = Dimension::of((15, 15));
+
+    // when
+    let result = dimension.edge_distance(&location);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((2, 2, 2)), 2)]
+#[case(Location::at((2, 2, 0)), 0)]
+#[case(Location::at((2, 1, 3)), 1)]
+fn dimension_edge_distance_3d(#[case] location: Location, #[case] expected: i32) {
+    // given
+    let dimension = Dimension::of((5, 5, 5));
+
+    // when
+    let result = dimension.edge_distance(&location);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn dimension_edge_distance_single() {
+    // given
+    let dimension = Dimension::of((1, 1, 1));
+
+    // when
+    let result = dimension.edge_distance(&Location::at((0, 0, 0)));
+
+    // then
+    assert_eq!(result, 0);
+}
+
+#[rstest]
+#[case(vec![], true)]
+#[case(vec![Location::at((0, 0, 0))], true)]
+#[case(vec![Location::at((0, 0, 0)), Location::at((1, 1, 1))], true)]
+#[case(vec![Location::at((0, 0, 0)), Location::at((2, 1, 1))], false)]
+#[case(vec![Location::at((-1, 0, 0)), Location::at((1, 1, 1))], false)]
+#[case(vec![Location::at((0, 0, i32::MAX)), Location::at((1, 1, 1))], false)]
+fn dimension_contains_all(#[case] locations: Vec<Location>, #[case] expected: bool) {
+    // given
+    let dimension = Dimension::of((2, 2, 2));
+
+    // when
+    let result = dimension.contains_all(&locations);
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(result, locations.iter().all(|l| dimension.contains(l)));
+}
+
+#[test]
+fn dimension_contains_all_large() {
+    // given
+    let dimension = Dimension::of((100, 100, 100));
+    let mut locations: Vec<Location> = (0..1_000_000)
+        .map(|i| Location::at((i % 100, (i / 100) % 100, i / 10_000)))
+        .collect();
+
+    // when
+    let inside = dimension.contains_all(&locations);
+    locations.push(Location::at((100, 0, 0)));
+    let outside = dimension.contains_all(&locations);
+
+    // then
+    assert!(inside);
+    assert!(!outside);
+}
+
+#[rstest]
+#[case(Dimension::of((1, 1, 1)))]
+#[case(Dimension::of((3, 2)))]
+#[case(Dimension::of((4, 3, 2)))]
+#[case(Dimension::of((15, 15)))]
+fn dimension_locations(#[case] dimension: Dimension) {
+    // when
+    let result: Vec<Location> = dimension.locations().collect();
+
+    // then
+    assert_eq!(result.len() as u64, dimension.volume());
+    assert!(result.iter().all(|l| dimension.contains(l)));
+    assert_eq!(result.iter().collect::<HashSet<_>>().len(), result.len());
+}
+
+#[test]
+fn dimension_locations_order() {
+    // given
+    let dimension = Dimension::of((2, 2, 2));
+
+    // when
+    let result: Vec<Location> = dimension.locations().collect();
+
+    // then
+    assert_eq!(
+        result,
+        vec![
+            Location::at((0, 0, 0)),
+            Location::at((1, 0, 0)),
+            Location::at((0, 1, 0)),
+            Location::at((1, 1, 0)),
+            Location::at((0, 0, 1)),
+            Location::at((1, 0, 1)),
+            Location::at((0, 1, 1)),
+            Location::at((1, 1, 1)),
+        ]
+    );
+}
+
+#[rstest]
+#[case(Location::at((0, 0, 0)), Location::at((4, 4, 0)), true)]
+#[case(Location::at((0, 2, 0)), Location::at((4, 2, 0)), true)]
+#[case(Location::at((3, 3, 0)), Location::at((3, 3, 0)), true)]
+#[case(Location::at((1, 2, 0)), Location::at((5, 2, 0)), false)]
+#[case(Location::at((2, -1, 0)), Location::at((2, 3, 0)), false)]
+#[case(Location::at((2, 2, 0)), Location::at((2, 2, 1)), false)]
+fn dimension_contains_line(#[case] start: Location, #[case] end: Location, #[case] expected: bool) {
+    // given
+    let dimension = Dimension::of((5, 5, 1));
+    let line = Line::between(&start, &end);
+
+    // when
+    let result = dimension.contains_line(&line);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((-3, 2, 0)), Location::at((0, 2, 0)))]
+#[case(Location::at((2, 2, 9)), Location::at((2, 2, 2)))]
+#[case(Location::at((2, 2, 1)), Location::at((2, 2, 1)))]
+#[case(Location::at((7, -1, 3)), Location::at((4, 0, 2)))]
+#[case(Location::at((i32::MIN, i32::MAX, 0)), Location::at((0, 3, 0)))]
+fn dimension_clamp_location(#[case] location: Location, #[case] expected: Location) {
+    // given
+    let dimension = Dimension::of((5, 4, 3));
+
+    // when
+    let result = dimension.clamp_location(&location);
+
+    // then
+    assert_eq!(result, expected);
+    assert!(dimension.contains(&result));
+}
+
 #[rstest]
 #[case(1, 1, 1, 1, 1, 1, Ordering::Equal)]
 #[case(1, 1, 1, 2, 1, 1, Ordering::Less)]
@@ -122,6 +428,127 @@ fn dimension_cmp(
 
 // Dimension end =====
 
+// OccupancyBitmap start =====
+
+#[rstest]
+#[case(Dimension::of((15, 15)))]
+#[case(Dimension::of((7, 5, 3)))]
+#[case(Dimension::of((1, 1, 1)))]
+fn occupancy_bitmap_new(#[case] dimension: Dimension) {
+    // when
+    let result = OccupancyBitmap::new(&dimension).unwrap();
+
+    // then
+    assert_eq!(result.dimension(), &dimension);
+    assert_eq!(result.count(), 0);
+    assert!(dimension.snake_locations().all(|l| !result.get(&l)));
+}
+
+#[rstest]
+#[case(Dimension::of((4096, 4096, 2)))]
+#[case(Dimension::of((u32::MAX, u32::MAX, 1)))]
+fn occupancy_bitmap_new_too_large(#[case] dimension: Dimension) {
+    // when
+    let result = OccupancyBitmap::new(&dimension);
+
+    // then
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::InvalidDimension)));
+}
+
+#[test]
+fn occupancy_bitmap_set_clear_get() {
+    // given
+    let mut bitmap = OccupancyBitmap::new(&Dimension::of((7, 5, 3))).unwrap();
+    let location = Location::at((6, 4, 2));
+
+    // when
+    let first_set = bitmap.set(&location);
+    let second_set = bitmap.set(&location);
+    let occupied = bitmap.get(&location);
+    let first_clear = bitmap.clear(&location);
+    let second_clear = bitmap.clear(&location);
+
+    // then
+    assert!(first_set);
+    assert!(!second_set);
+    assert!(occupied);
+    assert!(first_clear);
+    assert!(!second_clear);
+    assert!(!bitmap.get(&location));
+    assert_eq!(bitmap.count(), 0);
+}
+
+#[rstest]
+#[case(Location::at((-1, 0, 0)))]
+#[case(Location::at((0, -1, 0)))]
+#[case(Location::at((0, 0, -1)))]
+#[case(Location::at((7, 0, 0)))]
+#[case(Location::at((0, 5, 0)))]
+#[case(Location::at((0, 0, 3)))]
+fn occupancy_bitmap_outside(#[case] location: Location) {
+    // given
+    let mut bitmap = OccupancyBitmap::new(&Dimension::of((7, 5, 3))).unwrap();
+
+    // when
+    let set = bitmap.set(&location);
+
+    // then
+    assert!(!set);
+    assert!(!bitmap.get(&location));
+    assert!(!bitmap.clear(&location));
+    assert_eq!(bitmap.count(), 0);
+}
+
+#[rstest]
+#[case(Dimension::of((15, 15)), 1)]
+#[case(Dimension::of((15, 15)), 42)]
+#[case(Dimension::of((9, 8, 7)), 7)]
+#[case(Dimension::of((9, 8, 7)), 1234)]
+fn occupancy_bitmap_matches_hash_set(#[case] dimension: Dimension, #[case] seed: u64) {
+    // given
+    let mut bitmap = OccupancyBitmap::new(&dimension).unwrap();
+    let mut expected = HashSet::new();
+    let mut state = seed;
+    let mut next = |bound: u32| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % (bound as u64 + 2)) as i32 - 1
+    };
+
+    // when
+    for _ in 0..500 {
+        let location = Location::at((
+            next(dimension.width()),
+            next(dimension.height()),
+            next(dimension.depth()),
+        ));
+        if next(1) == 0 {
+            assert_eq!(
+                bitmap.clear(&location),
+                expected.remove(&location),
+                "{location:?}"
+            );
+        } else if dimension.contains(&location) {
+            assert_eq!(
+                bitmap.set(&location),
+                expected.insert(location),
+                "{location:?}"
+            );
+        } else {
+            assert!(!bitmap.set(&location), "{location:?}");
+        }
+    }
+
+    // then
+    assert_eq!(bitmap.count(), expected.len());
+    assert!(dimension
+        .snake_locations()
+        .all(|l| bitmap.get(&l) == expected.contains(&l)));
+}
+
+// OccupancyBitmap end =====
+
 // Distance start =====
 
 #[test]
@@ -147,31 +574,25 @@ fn distance_max() {
 }
 
 #[rstest]
-#[case(1, 1, 1, 2, 2, 2, 1, 1, 1)]
-#[case(2, 2, 2, 1, 1, 1, 1, 1, 1)]
-#[case(0, 0, 0, 1, 0, 0, 1, 0, 0)]
-#[case(0, 0, 0, 0, 1, 0, 0, 1, 0)]
-#[case(0, 0, 0, 0, 0, 1, 0, 0, 1)]
+#[case((1, 1, 1), (2, 2, 2), (1, 1, 1))]
+#[case((2, 2, 2), (1, 1, 1), (1, 1, 1))]
+#[case((0, 0, 0), (1, 0, 0), (1, 0, 0))]
+#[case((0, 0, 0), (0, 1, 0), (0, 1, 0))]
+#[case((0, 0, 0), (0, 0, 1), (0, 0, 1))]
 fn distance_between(
-    #[case] start_x: i32,
-    #[case] start_y: i32,
-    #[case] start_z: i32,
-    #[case] end_x: i32,
-    #[case] end_y: i32,
-    #[case] end_z: i32,
-    #[case] distance_x: i32,
-    #[case] distance_y: i32,
-    #[case] distance_z: i32,
+    #[case] start: (i32, i32, i32),
+    #[case] end: (i32, i32, i32),
+    #[case] distance: (i32, i32, i32),
 ) {
     // given
-    let start = Location::at((start_x, start_y, start_z));
-    let end = Location::at((end_x, end_y, end_z));
+    let start = Location::at(start);
+    let end = Location::at(end);
 
     // when
     let result = Distance::between(&start, &end);
 
     // then
-    assert_eq!(result, Distance::of((distance_x, distance_y, distance_z)));
+    assert_eq!(result, Distance::of(distance));
 }
 
 #[test]
@@ -219,6 +640,24 @@ fn distance_of_xyz_negative(#[case] x: i32, #[case] y: i32, #[case] z: i32) {
     assert_eq!(result.z(), z.abs());
 }
 
+#[test]
+fn distance_from_xy() {
+    // when
+    let result: Distance = (-1, 2).into();
+
+    // then
+    assert_eq!(result, Distance::of((1, 2, 0)));
+}
+
+#[test]
+fn distance_from_xyz() {
+    // when
+    let result: Distance = (1, -2, 3).into();
+
+    // then
+    assert_eq!(result, Distance::of((1, 2, 3)));
+}
+
 #[rstest]
 #[case(0, 0, 0, true)]
 #[case(1, 0, 0, true)]
@@ -242,6 +681,25 @@ fn distance_is_within(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] ex
     assert_eq!(result, expected)
 }
 
+#[test]
+fn distance_is_within_reused_bound() {
+    // given
+    let bound = Distance::of((2, 1, 0));
+    let candidates = [
+        Distance::of((2, 1, 0)),
+        Distance::of((0, 0, 0)),
+        Distance::of((3, 0, 0)),
+        Distance::of((1, 1, 1)),
+    ];
+
+    // when
+    let result: Vec<bool> = candidates.iter().map(|c| c.is_within(&bound)).collect();
+
+    // then
+    assert_eq!(result, vec![true, true, false, false]);
+    assert_eq!(bound, Distance::of((2, 1, 0)));
+}
+
 #[rstest]
 #[case(1, 1, 1, 1, 1, 1, Ordering::Equal)]
 #[case(1, 1, 1, 2, 1, 1, Ordering::Less)]
@@ -268,56 +726,303 @@ fn distance_cmp(
     assert_eq!(result, expected);
 }
 
-// Distance end =====
-
-// Line start =====
-
-#[test]
-fn line_start() {
+#[rstest]
+#[case(0, 0, 0, 0)]
+#[case(1, 0, 0, 1)]
+#[case(3, 4, 0, 4)]
+#[case(1, 1, 5, 5)]
+fn distance_chebyshev(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] expected: i32) {
     // given
-    let start = Location::at((1, 2, 3));
-    let end = Location::at((4, 5, 6));
+    let distance = Distance::of((x, y, z));
 
     // when
-    let result = Line::between(&start, &end);
+    let result = distance.chebyshev();
 
     // then
-    assert_eq!(result.start(), &start);
+    assert_eq!(result, expected);
 }
 
-#[test]
-fn line_end() {
+#[rstest]
+#[case(0, 0, 0, 0)]
+#[case(1, 0, 0, 1)]
+#[case(3, -4, 0, 7)]
+#[case(1, 1, 5, 7)]
+fn distance_manhattan(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] expected: i32) {
     // given
-    let start = Location::at((1, 2, 3));
-    let end = Location::at((4, 5, 6));
+    let distance = Distance::of((x, y, z));
 
     // when
-    let result = Line::between(&start, &end);
+    let result = distance.manhattan();
 
     // then
-    assert_eq!(result.end(), &end);
+    assert_eq!(result, expected);
 }
 
 #[test]
-fn line_point() {
-    // given
-    let point = Location::at((1, 1, 1));
-
+fn distance_manhattan_max() {
     // when
-    let result = Line::between(&point, &point);
+    let result = Distance::max().manhattan();
 
     // then
-    assert_eq!(result.start(), result.end());
+    assert_eq!(result, i32::MAX);
 }
 
 #[rstest]
-#[case(0, 1, 1, false)]
-#[case(1, 0, 1, false)]
-#[case(1, 1, 0, false)]
-#[case(1, 1, 1, true)]
-#[case(2, 1, 1, true)]
-#[case(3, 1, 1, true)]
-#[case(4, 1, 1, true)]
+#[case(Distance::of((1, 1, 1)), Distance::of((1, 1, 1)), 0, true)]
+#[case(Distance::of((1, 1, 1)), Distance::of((2, 1, 1)), 0, false)]
+#[case(Distance::of((1, 1, 1)), Distance::of((2, 0, 2)), 1, true)]
+#[case(Distance::of((1, 1, 1)), Distance::of((1, 3, 1)), 1, false)]
+fn distance_approx_eq(
+    #[case] lhs: Distance,
+    #[case] rhs: Distance,
+    #[case] tolerance: i32,
+    #[case] expected: bool,
+) {
+    // when
+    let result = lhs.approx_eq(&rhs, tolerance);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn distance_by_chebyshev_sort() {
+    // given
+    let mut distances = vec![
+        Distance::of((2, 0, 0)),
+        Distance::of((1, 5, 0)),
+        Distance::of((0, 1, 1)),
+    ];
+
+    // when
+    distances.sort_by_key(|d| d.by_chebyshev());
+
+    // then
+    assert_eq!(
+        distances,
+        vec![
+            Distance::of((0, 1, 1)),
+            Distance::of((2, 0, 0)),
+            Distance::of((1, 5, 0)),
+        ]
+    );
+}
+
+#[test]
+fn distance_by_manhattan_sort() {
+    // given
+    let mut distances = vec![
+        Distance::of((3, 0, 0)),
+        Distance::of((1, 1, 0)),
+        Distance::of((2, 2, 2)),
+        Distance::of((0, 2, 0)),
+    ];
+
+    // when
+    distances.sort_by_key(|d| d.by_manhattan());
+
+    // then
+    assert_eq!(
+        distances,
+        vec![
+            Distance::of((0, 2, 0)),
+            Distance::of((1, 1, 0)),
+            Distance::of((3, 0, 0)),
+            Distance::of((2, 2, 2)),
+        ]
+    );
+}
+
+#[rstest]
+#[case(Distance::of((1, 1, 1)), Distance::of((1, 1, 1)), Ordering::Equal)]
+#[case(Distance::of((2, 0, 0)), Distance::of((1, 5, 0)), Ordering::Less)]
+#[case(Distance::of((1, 2, 0)), Distance::of((2, 1, 0)), Ordering::Less)]
+fn distance_by_chebyshev_cmp(
+    #[case] lhs: Distance,
+    #[case] rhs: Distance,
+    #[case] expected: Ordering,
+) {
+    // when
+    let result = lhs.by_chebyshev().cmp(&rhs.by_chebyshev());
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(result == Ordering::Equal, lhs == rhs);
+}
+
+#[rstest]
+#[case(Distance::of((1, 1, 1)), Distance::of((1, 1, 1)), Ordering::Equal)]
+#[case(Distance::of((2, 0, 0)), Distance::of((1, 5, 0)), Ordering::Less)]
+#[case(Distance::of((0, 3, 0)), Distance::of((1, 1, 0)), Ordering::Greater)]
+#[case(Distance::of((2, 0, 0)), Distance::of((1, 2, 0)), Ordering::Less)]
+#[case(Distance::of((2, 2, 0)), Distance::of((0, 2, 1)), Ordering::Greater)]
+#[case(Distance::of((1, 2, 0)), Distance::of((2, 1, 0)), Ordering::Less)]
+#[case(Distance::of((0, 1, 1)), Distance::of((1, 0, 1)), Ordering::Less)]
+fn distance_total_cmp_by_magnitude(
+    #[case] lhs: Distance,
+    #[case] rhs: Distance,
+    #[case] expected: Ordering,
+) {
+    // when
+    let result = lhs.total_cmp_by_magnitude(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(rhs.total_cmp_by_magnitude(&lhs), expected.reverse());
+}
+
+#[test]
+fn distance_total_cmp_by_magnitude_sort() {
+    // given
+    let mut distances = vec![
+        Distance::of((3, 0, 0)),
+        Distance::of((0, 2, 2)),
+        Distance::of((2, 0, 0)),
+        Distance::of((1, 1, 1)),
+        Distance::of((0, 1, 0)),
+    ];
+
+    // when
+    distances.sort_by(|a, b| a.total_cmp_by_magnitude(b));
+
+    // then
+    assert_eq!(
+        distances,
+        vec![
+            Distance::of((0, 1, 0)),
+            Distance::of((1, 1, 1)),
+            Distance::of((2, 0, 0)),
+            Distance::of((0, 2, 2)),
+            Distance::of((3, 0, 0)),
+        ]
+    );
+}
+
+#[rstest]
+#[case(0, 0, 0, 0)]
+#[case(1, 0, 0, 1)]
+#[case(3, 4, 0, 25)]
+#[case(-3, -4, 0, 25)]
+#[case(1, 2, 3, 14)]
+fn distance_squared_length(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] expected: i64) {
+    // given
+    let distance = Distance::of((x, y, z));
+
+    // when
+    let result = distance.squared_length();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn distance_squared_length_max() {
+    // when
+    let result = Distance::max().squared_length();
+
+    // then
+    assert_eq!(result, i64::MAX);
+}
+
+#[test]
+fn distance_squared_length_cmp() {
+    // given
+    let near = Distance::of((3, 4, 0));
+    let far = Distance::of((1, 1, 5));
+
+    // when
+    let result = near.squared_length().cmp(&far.squared_length());
+
+    // then
+    assert_eq!(result, Ordering::Less);
+}
+
+// Distance end =====
+
+// Line start =====
+
+#[test]
+fn line_start() {
+    // given
+    let start = Location::at((1, 2, 3));
+    let end = Location::at((4, 5, 6));
+
+    // when
+    let result = Line::between(&start, &end);
+
+    // then
+    assert_eq!(result.start(), &start);
+}
+
+#[test]
+fn line_end() {
+    // given
+    let start = Location::at((1, 2, 3));
+    let end = Location::at((4, 5, 6));
+
+    // when
+    let result = Line::between(&start, &end);
+
+    // then
+    assert_eq!(result.end(), &end);
+}
+
+#[test]
+fn line_start_end_backward() {
+    // given
+    let start = Location::at((4, 5, 6));
+    let end = Location::at((1, 2, 3));
+
+    // when
+    let result = Line::between(&start, &end);
+
+    // then
+    assert_eq!(result.start(), &start);
+    assert_eq!(result.end(), &end);
+    assert_eq!(result.iter().next(), Option::Some(&start));
+    assert_eq!(result.iter().last(), Option::Some(&end));
+}
+
+#[test]
+fn line_eq_opposite_directions() {
+    // given
+    let start = Location::at((1, 1, 1));
+    let end = Location::at((4, 1, 1));
+
+    // when
+    let forward = Line::between(&start, &end);
+    let backward = Line::between(&end, &start);
+
+    // then
+    assert_eq!(forward, backward);
+    assert_eq!(forward.cmp(&backward), Ordering::Equal);
+    assert_eq!(HashSet::from([forward, backward]).len(), 1);
+    assert_ne!(
+        Line::between(&start, &end),
+        Line::between(&start, &Location::at((3, 1, 1)))
+    );
+}
+
+#[test]
+fn line_point() {
+    // given
+    let point = Location::at((1, 1, 1));
+
+    // when
+    let result = Line::between(&point, &point);
+
+    // then
+    assert_eq!(result.start(), result.end());
+}
+
+#[rstest]
+#[case(0, 1, 1, false)]
+#[case(1, 0, 1, false)]
+#[case(1, 1, 0, false)]
+#[case(1, 1, 1, true)]
+#[case(2, 1, 1, true)]
+#[case(3, 1, 1, true)]
+#[case(4, 1, 1, true)]
 #[case(5, 1, 1, false)]
 #[case(2, 2, 1, false)]
 #[case(2, 0, 1, false)]
@@ -396,211 +1101,1041 @@ fn line_contains_diagonal(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case
     assert_eq!(result, expected);
 }
 
-// Line start =====
+#[rstest]
+#[case(0, 0, 0, false)]
+#[case(1, 1, 1, true)]
+#[case(2, 2, 2, true)]
+#[case(3, 3, 3, true)]
+#[case(4, 4, 4, true)]
+#[case(5, 5, 5, false)]
+fn line_contains_backward(#[case] x: i32, #[case] y: i32, #[case] z: i32, #[case] expected: bool) {
+    // given
+    let line = Line::between(&Location::at((4, 4, 4)), &Location::at((1, 1, 1)));
 
-// Location start =====
+    // when
+    let result = line.contains(&Location::at((x, y, z)));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((0, 0, 0)), Location::at((6, 2, 0)), vec![(0, 0, 0), (1, 0, 0), (2, 1, 0), (3, 1, 0), (4, 1, 0), (5, 2, 0), (6, 2, 0)])]
+#[case(Location::at((6, 2, 0)), Location::at((0, 0, 0)), vec![(6, 2, 0), (5, 2, 0), (4, 1, 0), (3, 1, 0), (2, 1, 0), (1, 0, 0), (0, 0, 0)])]
+#[case(Location::at((0, 0, 0)), Location::at((4, -2, 1)), vec![(0, 0, 0), (1, -1, 0), (2, -1, 1), (3, -2, 1), (4, -2, 1)])]
+#[case(Location::at((2, 2, 2)), Location::at((2, 2, 2)), vec![(2, 2, 2)])]
+fn line_between_exact(
+    #[case] start: Location,
+    #[case] end: Location,
+    #[case] expected: Vec<(i32, i32, i32)>,
+) {
+    // when
+    let result = Line::between(&start, &end);
+
+    // then
+    let expected: Vec<Location> = expected.into_iter().map(Location::from).collect();
+    assert_eq!(result.ordered_locations(), expected);
+}
 
 #[test]
-fn location_at_xy() {
+fn line_between_long() {
     // given
-    let x = 1;
-    let y = 2;
+    let start = Location::at((-500, 17, 3));
+    let end = Location::at((1500, -650, 10));
 
     // when
-    let result = Location::at((x, y));
+    let result = Line::between(&start, &end).ordered_locations();
 
     // then
-    assert_eq!(result.x(), x);
-    assert_eq!(result.y(), y);
-    assert_eq!(result.z(), 0);
+    assert_eq!(result.len(), 2001);
+    assert_eq!(result.first(), Some(&start));
+    assert_eq!(result.last(), Some(&end));
+    assert!(result.windows(2).all(|pair| {
+        let step = Vector::from(&pair[0], &pair[1]);
+        step.x() == 1 && step.y() >= -1 && step.y() <= 0 && step.z() >= 0 && step.z() <= 1
+    }));
 }
 
 #[test]
-fn location_at_xyz() {
+fn line_ordered_locations_forward() {
     // given
-    let x = 1;
-    let y = 2;
-    let z = 3;
+    let line = Line::between(&Location::at((1, 1, 1)), &Location::at((4, 1, 1)));
 
     // when
-    let result = Location::at((x, y, z));
+    let result = line.ordered_locations();
 
     // then
-    assert_eq!(result.x(), x);
-    assert_eq!(result.y(), y);
-    assert_eq!(result.z(), z);
+    assert_eq!(
+        result,
+        vec![
+            Location::at((1, 1, 1)),
+            Location::at((2, 1, 1)),
+            Location::at((3, 1, 1)),
+            Location::at((4, 1, 1)),
+        ]
+    );
 }
 
 #[test]
-fn location_go_positive() {
+fn line_ordered_locations_backward() {
     // given
-    let location = Location::at((1, 1, 1));
-    let vector = Vector::of((1, 1, 1));
+    let line = Line::between(&Location::at((4, 1, 1)), &Location::at((1, 1, 1)));
 
     // when
-    let result = location.go(&vector);
+    let result = line.ordered_locations();
 
     // then
-    assert_eq!(result, Location::at((2, 2, 2)));
+    assert_eq!(
+        result,
+        vec![
+            Location::at((4, 1, 1)),
+            Location::at((3, 1, 1)),
+            Location::at((2, 1, 1)),
+            Location::at((1, 1, 1)),
+        ]
+    );
 }
 
 #[test]
-fn location_go_negative() {
+fn line_iter_backward() {
     // given
-    let location = Location::at((2, 2, 2));
-    let vector = Vector::of((-1, -1, -1));
+    let line = Line::between(&Location::at((4, 4, 4)), &Location::at((1, 1, 1)));
 
     // when
-    let result = location.go(&vector);
+    let result: Vec<&Location> = line.iter().collect();
 
     // then
-    assert_eq!(result, Location::at((1, 1, 1)));
+    assert_eq!(result.first(), Some(&&Location::at((4, 4, 4))));
+    assert_eq!(result.last(), Some(&&Location::at((1, 1, 1))));
+    assert_eq!(result.len(), 4);
+}
+
+#[test]
+fn line_into_iter_backward() {
+    // given
+    let line = Line::between(&Location::at((4, 4, 4)), &Location::at((1, 1, 1)));
+    let expected = line.ordered_locations();
+
+    // when
+    let borrowed: Vec<Location> = (&line).into_iter().copied().collect();
+    let owned: Vec<Location> = line.into_iter().collect();
+
+    // then
+    assert_eq!(owned.first(), Some(&Location::at((4, 4, 4))));
+    assert_eq!(owned, expected);
+    assert_eq!(borrowed, expected);
+}
+
+#[test]
+fn line_between_connected_ordered_locations() {
+    // given
+    let line = Line::between_connected(&Location::at((4, 2, 0)), &Location::at((0, 0, 0)));
+
+    // when
+    let result = line.ordered_locations();
+
+    // then
+    assert_eq!(result.len(), 7);
+    assert_eq!(result.first(), Some(&Location::at((4, 2, 0))));
+    assert_eq!(result.last(), Some(&Location::at((0, 0, 0))));
+    for pair in result.windows(2) {
+        assert!(Distance::between(&pair[0], &pair[1]).is_within(&Distance::of((1, 1, 1))));
+    }
 }
 
 #[rstest]
-#[case(1, 1, 1, 3, 3, 3, true)]
-#[case(2, 1, 1, 4, 3, 3, true)]
-#[case(1, 2, 1, 3, 4, 3, true)]
-#[case(1, 1, 2, 3, 3, 4, true)]
-#[case(1, 1, 1, 1, 1, 1, true)]
-#[case(1, 1, 1, 4, 4, 4, false)]
-#[case(1, 1, 1, 4, 3, 3, false)]
-#[case(1, 1, 1, 3, 4, 3, false)]
-#[case(1, 1, 1, 3, 3, 4, false)]
-fn location_is_within(
-    #[case] distance_x: i32,
-    #[case] distance_y: i32,
-    #[case] distance_z: i32,
-    #[case] target_x: i32,
-    #[case] target_y: i32,
-    #[case] target_z: i32,
+#[case(0, 0, 0, true)]
+#[case(1, 0, 0, true)]
+#[case(1, 1, 0, true)]
+#[case(2, 1, 0, true)]
+#[case(3, 1, 0, true)]
+#[case(3, 2, 0, true)]
+#[case(4, 2, 0, true)]
+#[case(2, 0, 0, false)]
+#[case(2, 2, 0, false)]
+#[case(0, 1, 0, false)]
+#[case(4, 1, 0, false)]
+fn line_between_connected_contains(
+    #[case] x: i32,
+    #[case] y: i32,
+    #[case] z: i32,
     #[case] expected: bool,
 ) {
     // given
-    let start = Location::at((2, 2, 2));
-    let distance = Distance::of((distance_x, distance_y, distance_z));
-    let target = Location::at((target_x, target_y, target_z));
+    let line = Line::between_connected(&Location::at((0, 0, 0)), &Location::at((4, 2, 0)));
+
+    // when
+    let result = line.contains(&Location::at((x, y, z)));
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn line_between_connected_adjacent() {
+    // given
+    let line = Line::between_connected(&Location::at((0, 0, 0)), &Location::at((4, 2, 0)));
+
+    // when
+    let path: Vec<Location> = (0..=4)
+        .flat_map(|x| (0..=2).map(move |y| Location::at((x, y, 0))))
+        .filter(|location| line.contains(location))
+        .collect();
+
+    // then
+    assert_eq!(path.first(), Some(&Location::at((0, 0, 0))));
+    assert_eq!(path.last(), Some(&Location::at((4, 2, 0))));
+    for pair in path.windows(2) {
+        let distance = Distance::between(&pair[0], &pair[1]);
+        assert_eq!(distance.x() + distance.y() + distance.z(), 1);
+        assert!(distance.is_within(&Distance::of((1, 1, 1))));
+    }
+}
+
+#[test]
+fn line_between_connected_backward() {
+    // given
+    let start = Location::at((4, 4, 4));
+    let end = Location::at((1, 2, 3));
+
+    // when
+    let result = Line::between_connected(&start, &end);
+
+    // then
+    assert_eq!(result.start(), &start);
+    assert_eq!(result.end(), &end);
+    assert!(!result.contains(&Location::at((5, 4, 4))));
+    assert!(!result.contains(&Location::at((0, 2, 3))));
+}
+
+#[test]
+fn line_between_connected_point() {
+    // given
+    let point = Location::at((1, 1, 1));
+
+    // when
+    let result = Line::between_connected(&point, &point);
+
+    // then
+    assert_eq!(result.start(), &point);
+    assert_eq!(result.end(), &point);
+}
+
+// Line end =====
+
+// Location start =====
+
+#[test]
+fn location_at_xy() {
+    // given
+    let x = 1;
+    let y = 2;
+
+    // when
+    let result = Location::at((x, y));
+
+    // then
+    assert_eq!(result.x(), x);
+    assert_eq!(result.y(), y);
+    assert_eq!(result.z(), 0);
+}
+
+#[test]
+fn location_at_xyz() {
+    // given
+    let x = 1;
+    let y = 2;
+    let z = 3;
+
+    // when
+    let result = Location::at((x, y, z));
+
+    // then
+    assert_eq!(result.x(), x);
+    assert_eq!(result.y(), y);
+    assert_eq!(result.z(), z);
+}
+
+#[test]
+fn location_from_xy() {
+    // when
+    let result: Location = (1, 2).into();
+
+    // then
+    assert_eq!(result, Location::at((1, 2, 0)));
+}
+
+#[test]
+fn location_from_xyz() {
+    // when
+    let result: Location = (1, 2, 3).into();
+
+    // then
+    assert_eq!(result, Location::at((1, 2, 3)));
+}
+
+#[test]
+fn location_go_positive() {
+    // given
+    let location = Location::at((1, 1, 1));
+    let vector = Vector::of((1, 1, 1));
+
+    // when
+    let result = location.go(&vector);
+
+    // then
+    assert_eq!(result, Location::at((2, 2, 2)));
+}
+
+#[test]
+fn location_go_negative() {
+    // given
+    let location = Location::at((2, 2, 2));
+    let vector = Vector::of((-1, -1, -1));
+
+    // when
+    let result = location.go(&vector);
+
+    // then
+    assert_eq!(result, Location::at((1, 1, 1)));
+}
+
+#[rstest]
+#[case(1, 1, 1, 3, 3, 3, true)]
+#[case(2, 1, 1, 4, 3, 3, true)]
+#[case(1, 2, 1, 3, 4, 3, true)]
+#[case(1, 1, 2, 3, 3, 4, true)]
+#[case(1, 1, 1, 1, 1, 1, true)]
+#[case(1, 1, 1, 4, 4, 4, false)]
+#[case(1, 1, 1, 4, 3, 3, false)]
+#[case(1, 1, 1, 3, 4, 3, false)]
+#[case(1, 1, 1, 3, 3, 4, false)]
+fn location_is_within(
+    #[case] distance_x: i32,
+    #[case] distance_y: i32,
+    #[case] distance_z: i32,
+    #[case] target_x: i32,
+    #[case] target_y: i32,
+    #[case] target_z: i32,
+    #[case] expected: bool,
+) {
+    // given
+    let start = Location::at((2, 2, 2));
+    let distance = Distance::of((distance_x, distance_y, distance_z));
+    let target = Location::at((target_x, target_y, target_z));
+
+    // when
+    let result = start.is_within(&distance, &target);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(1, 1, 1, 1, 1, 1, Ordering::Equal)]
+#[case(1, 1, 1, 2, 1, 1, Ordering::Less)]
+#[case(1, 1, 1, 1, 2, 1, Ordering::Less)]
+#[case(1, 1, 1, 1, 1, 2, Ordering::Less)]
+#[case(2, 2, 2, 3, 1, 1, Ordering::Less)]
+fn location_cmp(
+    #[case] lhs_x: i32,
+    #[case] lhs_y: i32,
+    #[case] lhs_z: i32,
+    #[case] rhs_x: i32,
+    #[case] rhs_y: i32,
+    #[case] rhs_z: i32,
+    #[case] expected: Ordering,
+) {
+    // given
+    let lhs = Location::at((lhs_x, lhs_y, lhs_z));
+    let rhs = Location::at((rhs_x, rhs_y, rhs_z));
+
+    // when
+    let result = lhs.cmp(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((1, 1, 1)), Location::at((1, 1, 1)), 0, true)]
+#[case(Location::at((1, 1, 1)), Location::at((2, 1, 1)), 0, false)]
+#[case(Location::at((1, 1, 1)), Location::at((2, 1, 1)), 1, true)]
+#[case(Location::at((1, 1, 1)), Location::at((0, 0, 0)), 1, true)]
+#[case(Location::at((1, 1, 1)), Location::at((1, 1, 3)), 1, false)]
+fn location_approx_eq(
+    #[case] lhs: Location,
+    #[case] rhs: Location,
+    #[case] tolerance: i32,
+    #[case] expected: bool,
+) {
+    // when
+    let result = lhs.approx_eq(&rhs, tolerance);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((1, 5, 0)), Location::at((3, 2, 0)), Location::at((1, 2, 0)))]
+#[case(Location::at((3, 2, 0)), Location::at((1, 5, 0)), Location::at((1, 2, 0)))]
+#[case(Location::at((-1, 2, 3)), Location::at((1, -2, 3)), Location::at((-1, -2, 3)))]
+fn location_component_min(
+    #[case] lhs: Location,
+    #[case] rhs: Location,
+    #[case] expected: Location,
+) {
+    // when
+    let result = lhs.component_min(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((1, 5, 0)), Location::at((3, 2, 0)), Location::at((3, 5, 0)))]
+#[case(Location::at((3, 2, 0)), Location::at((1, 5, 0)), Location::at((3, 5, 0)))]
+#[case(Location::at((-1, 2, 3)), Location::at((1, -2, 3)), Location::at((1, 2, 3)))]
+fn location_component_max(
+    #[case] lhs: Location,
+    #[case] rhs: Location,
+    #[case] expected: Location,
+) {
+    // when
+    let result = lhs.component_max(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Location::at((1, 2, 3)), Location::at((1, 2, 3)), 0)]
+#[case(Location::at((0, 0, 0)), Location::at((3, 3, 3)), 9)]
+#[case(Location::at((3, 3, 3)), Location::at((0, 0, 0)), 9)]
+#[case(Location::at((1, 1, 0)), Location::at((4, -3, 0)), 7)]
+#[case(Location::at((-2, 5, 1)), Location::at((2, 5, -1)), 6)]
+fn location_manhattan_distance(
+    #[case] lhs: Location,
+    #[case] rhs: Location,
+    #[case] expected: i32,
+) {
+    // when
+    let result = lhs.manhattan_distance(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn location_neighbors_xy() {
+    // given
+    let location = Location::at((1, 1, 0));
+    let orientations: Vec<Box<dyn Orientation>> = Orientations::xy().into_iter().collect();
+
+    // when
+    let result = location.neighbors(&orientations);
+
+    // then
+    assert_eq!(
+        result,
+        vec![
+            Location::at((0, 1, 0)),
+            Location::at((2, 1, 0)),
+            Location::at((1, 0, 0)),
+            Location::at((1, 2, 0)),
+        ]
+    );
+}
+
+#[test]
+fn location_neighbors_xyz() {
+    // given
+    let location = Location::at((1, 1, 1));
+    let orientations: Vec<Box<dyn Orientation>> = Orientations::xyz().into_iter().collect();
+
+    // when
+    let result = location.neighbors(&orientations);
+
+    // then
+    assert_eq!(
+        result,
+        vec![
+            Location::at((0, 1, 1)),
+            Location::at((2, 1, 1)),
+            Location::at((1, 0, 1)),
+            Location::at((1, 2, 1)),
+            Location::at((1, 1, 0)),
+            Location::at((1, 1, 2)),
+        ]
+    );
+}
+
+#[test]
+fn location_neighbors_none() {
+    // when
+    let result = Location::at((1, 1, 1)).neighbors(&[]);
+
+    // then
+    assert!(result.is_empty());
+}
+
+#[rstest]
+#[case(Location::at((0, 0, 0)), Vector::of((0, 0, 0)), Location::at((0, 0, 0)))]
+#[case(Location::at((1, 2, 3)), Vector::of((3, 4, 5)), Location::at((4, 6, 8)))]
+#[case(Location::at((1, 2, 3)), Vector::of((-3, -4, -5)), Location::at((-2, -2, -2)))]
+#[case(Location::at((-2, 3, -1)), Vector::of((4, -6, 2)), Location::at((2, -3, 1)))]
+fn location_add(#[case] location: Location, #[case] vector: Vector, #[case] expected: Location) {
+    // when
+    let result = location + vector;
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(result, location.go(&vector));
+    assert_eq!(result, Add::add(&location, &vector));
+}
+
+#[rstest]
+#[case(Location::at((0, 0, 0)), Location::at((0, 0, 0)), Vector::of((0, 0, 0)))]
+#[case(Location::at((1, 2, 3)), Location::at((4, 6, 8)), Vector::of((3, 4, 5)))]
+#[case(Location::at((4, 6, 8)), Location::at((1, 2, 3)), Vector::of((-3, -4, -5)))]
+#[case(Location::at((-2, 3, -1)), Location::at((2, -3, 1)), Vector::of((4, -6, 2)))]
+#[case(Location::at((-5, -5, -5)), Location::at((-1, -7, 0)), Vector::of((4, -2, 5)))]
+fn location_sub(#[case] start: Location, #[case] end: Location, #[case] expected: Vector) {
+    // when
+    let result = end - start;
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(result, Vector::from(&start, &end));
+    assert_eq!(result, Sub::sub(&end, &start));
+    assert_eq!(start.go(&result), end);
+}
+
+// Location end =====
+
+// BoundingBox start =====
+
+#[test]
+fn bounding_box_empty() {
+    // when
+    let result = BoundingBox::from_locations(&[]);
+
+    // then
+    assert!(result.is_none());
+}
+
+#[test]
+fn bounding_box_single() {
+    // given
+    let location = Location::at((3, -2, 1));
+
+    // when
+    let result = BoundingBox::from_locations(&[location]).unwrap();
+
+    // then
+    assert_eq!(result.min(), &location);
+    assert_eq!(result.max(), &location);
+    assert_eq!(result.dimension(), Dimension::of((1, 1, 1)));
+}
+
+#[test]
+fn bounding_box_l_shape() {
+    // given
+    let locations = [
+        Location::at((2, 1, 0)),
+        Location::at((2, 2, 0)),
+        Location::at((2, 3, 0)),
+        Location::at((3, 3, 0)),
+        Location::at((4, 3, 0)),
+    ];
+
+    // when
+    let result = BoundingBox::from_locations(&locations).unwrap();
+
+    // then
+    assert_eq!(result.min(), &Location::at((2, 1, 0)));
+    assert_eq!(result.max(), &Location::at((4, 3, 0)));
+    assert_eq!(result.dimension(), Dimension::of((3, 3, 1)));
+}
+
+#[test]
+fn bounding_box_scattered() {
+    // given
+    let locations = [
+        Location::at((5, -1, 2)),
+        Location::at((-3, 4, 0)),
+        Location::at((0, 0, 7)),
+    ];
+
+    // when
+    let result = BoundingBox::from_locations(&locations).unwrap();
+
+    // then
+    assert_eq!(result.min(), &Location::at((-3, -1, 0)));
+    assert_eq!(result.max(), &Location::at((5, 4, 7)));
+    assert_eq!(result.dimension(), Dimension::of((9, 6, 8)));
+}
+
+// BoundingBox end =====
+
+// Orientations start =====
+
+#[rstest]
+#[case(Orientations::x(), Location::at((1, 1, 1)), -1, Location::at((0, 1, 1)))]
+#[case(Orientations::x(), Location::at((1, 1, 1)), 1, Location::at((2, 1, 1)))]
+#[case(Orientations::y(), Location::at((1, 1, 1)), -1, Location::at((1, 0, 1)))]
+#[case(Orientations::y(), Location::at((1, 1, 1)), 1, Location::at((1, 2, 1)))]
+#[case(Orientations::z(), Location::at((1, 1, 1)), -1, Location::at((1, 1, 0)))]
+#[case(Orientations::z(), Location::at((1, 1, 1)), 1, Location::at((1, 1, 2)))]
+#[case(Orientations::xy_diagonal(), Location::at((1, 1, 1)), -1, Location::at((0, 0, 1)))]
+#[case(Orientations::xy_diagonal(), Location::at((1, 1, 1)), 2, Location::at((3, 3, 1)))]
+#[case(Orientations::xyz_diagonal(), Location::at((1, 1, 1)), -1, Location::at((0, 0, 0)))]
+#[case(Orientations::xyz_diagonal(), Location::at((1, 1, 1)), 2, Location::at((3, 3, 3)))]
+fn orientations_go(
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] start: Location,
+    #[case] amount: i32,
+    #[case] end: Location,
+) {
+    // when
+    let result = orientation.go(&start, amount);
+
+    // then
+    assert_eq!(result, end);
+}
+
+#[rstest]
+#[case(Orientations::x(), Distance::of((1, 0, 0)), true)]
+#[case(Orientations::x(), Distance::of((0, 1, 0)), false)]
+#[case(Orientations::x(), Distance::of((0, 0, 1)), false)]
+#[case(Orientations::y(), Distance::of((1, 0, 0)), false)]
+#[case(Orientations::y(), Distance::of((0, 1, 0)), true)]
+#[case(Orientations::y(), Distance::of((0, 0, 1)), false)]
+#[case(Orientations::z(), Distance::of((1, 0, 0)), false)]
+#[case(Orientations::z(), Distance::of((0, 1, 0)), false)]
+#[case(Orientations::z(), Distance::of((0, 0, 1)), true)]
+#[case(Orientations::xy_diagonal(), Distance::of((1, 1, 0)), true)]
+#[case(Orientations::xy_diagonal(), Distance::of((4, 4, 0)), true)]
+#[case(Orientations::xy_diagonal(), Distance::of((0, 0, 0)), false)]
+#[case(Orientations::xy_diagonal(), Distance::of((1, 1, 1)), false)]
+#[case(Orientations::xy_diagonal(), Distance::of((1, 0, 0)), false)]
+#[case(Orientations::xy_diagonal(), Distance::of((2, 1, 0)), false)]
+#[case(Orientations::xyz_diagonal(), Distance::of((1, 1, 1)), true)]
+#[case(Orientations::xyz_diagonal(), Distance::of((3, 3, 3)), true)]
+#[case(Orientations::xyz_diagonal(), Distance::of((0, 0, 0)), false)]
+#[case(Orientations::xyz_diagonal(), Distance::of((1, 1, 0)), false)]
+#[case(Orientations::xyz_diagonal(), Distance::of((1, 0, 0)), false)]
+#[case(Orientations::xyz_diagonal(), Distance::of((2, 1, 1)), false)]
+fn orientations_contains(
+    #[case] orientation: Box<dyn Orientation>,
+    #[case] distance: Distance,
+    #[case] expected: bool,
+) {
+    // when
+    let result = orientation.contains(&distance);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Orientations::x(), Orientations::x(), true)]
+#[case(Orientations::y(), Orientations::y(), true)]
+#[case(Orientations::z(), Orientations::z(), true)]
+#[case(Orientations::x(), Orientations::y(), false)]
+#[case(Orientations::x(), Orientations::z(), false)]
+#[case(Orientations::y(), Orientations::z(), false)]
+#[case(Orientations::xy_diagonal(), Orientations::xy_diagonal(), true)]
+#[case(Orientations::xy_diagonal(), Orientations::x(), false)]
+#[case(Orientations::xy_diagonal(), Orientations::xyz_diagonal(), false)]
+#[case(Orientations::xyz_diagonal(), Orientations::xyz_diagonal(), true)]
+#[case(Orientations::xyz_diagonal(), Orientations::x(), false)]
+fn orientations_eq(
+    #[case] lhs: Box<dyn Orientation>,
+    #[case] rhs: Box<dyn Orientation>,
+    #[case] expected: bool,
+) {
+    // when
+    let result = lhs == rhs;
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Vector::of((1, 0, 0)), Some(Orientations::x()))]
+#[case(Vector::of((-4, 0, 0)), Some(Orientations::x()))]
+#[case(Vector::of((0, 1, 0)), Some(Orientations::y()))]
+#[case(Vector::of((0, 7, 0)), Some(Orientations::y()))]
+#[case(Vector::of((0, 0, 1)), Some(Orientations::z()))]
+#[case(Vector::of((0, 0, -2)), Some(Orientations::z()))]
+#[case(Vector::of((1, 1, 0)), None)]
+#[case(Vector::of((1, 1, 1)), None)]
+#[case(Vector::of((0, 0, 0)), None)]
+fn orientations_axis_of(#[case] vector: Vector, #[case] expected: Option<Box<dyn Orientation>>) {
+    // when
+    let result = Orientations::axis_of(&vector);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Orientations::x())]
+#[case(Orientations::y())]
+#[case(Orientations::z())]
+fn orientations_axis_of_inverse(#[case] orientation: Box<dyn Orientation>) {
+    // when
+    let result = Orientations::axis_of(&orientation.vector());
+
+    // then
+    assert_eq!(result, Some(orientation));
+}
+
+#[test]
+fn orientations_xy() {
+    // when
+    let result = Orientations::xy();
+
+    // then
+    assert_eq!(&result[0], &Orientations::x());
+    assert_eq!(&result[1], &Orientations::y());
+}
+
+#[test]
+fn orientations_xyz() {
+    // when
+    let result = Orientations::xyz();
+
+    // then
+    assert_eq!(&result[0], &Orientations::x());
+    assert_eq!(&result[1], &Orientations::y());
+    assert_eq!(&result[2], &Orientations::z());
+}
+
+#[rstest]
+#[case(vec![Orientations::x()], Location::at((0, 0, 0)), Location::at((0, 0, 0)), Some(0))]
+#[case(vec![Orientations::x()], Location::at((0, 0, 0)), Location::at((5, 0, 0)), Some(5))]
+#[case(vec![Orientations::x()], Location::at((5, 0, 0)), Location::at((-2, 0, 0)), Some(7))]
+#[case(vec![Orientations::x()], Location::at((0, 0, 0)), Location::at((0, 1, 0)), None)]
+#[case(vec![Orientations::x(), Orientations::y()], Location::at((0, 0, 0)), Location::at((3, -2, 0)), Some(5))]
+#[case(vec![Orientations::x(), Orientations::y()], Location::at((0, 0, 0)), Location::at((3, 2, 1)), None)]
+#[case(vec![Orientations::xyz_diagonal()], Location::at((0, 0, 0)), Location::at((-3, -3, -3)), Some(3))]
+#[case(vec![Orientations::x(), Orientations::xyz_diagonal()], Location::at((0, 0, 0)), Location::at((0, 4, 4)), Some(8))]
+#[case(vec![Orientations::x(), Orientations::xyz_diagonal()], Location::at((0, 0, 0)), Location::at((0, 4, 3)), None)]
+#[case(vec![], Location::at((0, 0, 0)), Location::at((1, 0, 0)), None)]
+#[case(vec![<dyn Orientation>::from_vector(&Vector::of((7, 0, 0))), <dyn Orientation>::from_vector(&Vector::of((10, 0, 0)))], Location::at((0, 0, 0)), Location::at((1, 0, 0)), Some(5))]
+#[case(vec![<dyn Orientation>::from_vector(&Vector::of((0, 4, 0))), <dyn Orientation>::from_vector(&Vector::of((0, 6, 0)))], Location::at((0, 0, 0)), Location::at((0, 1, 0)), None)]
+#[case(vec![<dyn Orientation>::from_vector(&Vector::of((2, 3, 0))), Orientations::x()], Location::at((0, 0, 0)), Location::at((0, 9, 0)), Some(9))]
+fn orientation_distance_steps(
+    #[case] orientations: Vec<Box<dyn Orientation>>,
+    #[case] start: Location,
+    #[case] end: Location,
+    #[case] expected: Option<i32>,
+) {
+    // when
+    let result = orientation_distance(&start, &end, &orientations);
+
+    // then
+    assert_eq!(result.unwrap(), expected);
+}
+
+#[test]
+fn orientation_distance_limit() {
+    // given
+    let orientations = vec![Orientations::x(), Orientations::y()];
+    let start = Location::at((0, 0, 0));
+    let end = Location::at((5000, 5000, 0));
+
+    // when
+    let result = orientation_distance(&start, &end, &orientations);
+
+    // then
+    // end is reachable in 10000 steps, but the search gives up before finding it
+    assert!(matches!(result, Err(e) if matches!(e.kind, ErrorKind::SearchLimitExceeded)));
+}
+
+#[rstest]
+#[case(Orientations::x(), Vector::of((1, 0, 0)))]
+#[case(Orientations::y(), Vector::of((0, 1, 0)))]
+#[case(Orientations::z(), Vector::of((0, 0, 1)))]
+#[case(Orientations::xy_diagonal(), Vector::of((1, 1, 0)))]
+#[case(Orientations::xyz_diagonal(), Vector::of((1, 1, 1)))]
+fn orientation_vector(#[case] orientation: Box<dyn Orientation>, #[case] expected: Vector) {
+    // given
+    let location = Location::at((1, 2, 3));
+
+    // when
+    let result = orientation.vector();
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(location.go(&(result * 3)), orientation.go(&location, 3));
+}
+
+#[rstest]
+#[case(Orientations::x(), Vector::of((1, 0, 0)))]
+#[case(Orientations::y(), Vector::of((0, 1, 0)))]
+#[case(Orientations::z(), Vector::of((0, 0, 1)))]
+#[case(Orientations::xy_diagonal(), Vector::of((1, 1, 0)))]
+#[case(Orientations::xyz_diagonal(), Vector::of((1, 1, 1)))]
+fn orientation_to_vector(#[case] orientation: Box<dyn Orientation>, #[case] expected: Vector) {
+    // when
+    let result = orientation.to_vector();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Orientations::x())]
+#[case(Orientations::y())]
+#[case(Orientations::z())]
+#[case(Orientations::xy_diagonal())]
+#[case(Orientations::xyz_diagonal())]
+fn orientation_from_vector_built_in(#[case] orientation: Box<dyn Orientation>) {
+    // when
+    let result = <dyn Orientation>::from_vector(&orientation.to_vector());
+
+    // then
+    assert_eq!(&result, &orientation);
+}
+
+#[rstest]
+#[case(Orientations::x())]
+#[case(Orientations::y())]
+#[case(Orientations::z())]
+#[case(Orientations::xy_diagonal())]
+#[case(Orientations::xyz_diagonal())]
+fn orientation_reverse(#[case] orientation: Box<dyn Orientation>) {
+    // given
+    let location = Location::at((3, 4, 5));
+
+    // when
+    let result = orientation.reverse();
+
+    // then
+    assert_eq!(result.go(&location, 1), orientation.go(&location, -1));
+    assert_eq!(result.go(&location, -2), orientation.go(&location, 2));
+    assert_eq!(result.vector(), -orientation.vector());
+    assert_ne!(&result, &orientation);
+    assert_eq!(&result.reverse(), &orientation);
+}
+
+#[rstest]
+#[case(Distance::of((0, 0, 0)))]
+#[case(Distance::of((3, 0, 0)))]
+#[case(Distance::of((0, 3, 0)))]
+#[case(Distance::of((3, 3, 3)))]
+fn orientation_reverse_contains(#[case] distance: Distance) {
+    // given
+    let orientation = Orientations::x();
+
+    // when
+    let result = orientation.reverse().contains(&distance);
+
+    // then
+    assert_eq!(result, orientation.contains(&distance));
+}
+
+#[rstest]
+#[case(Vector::of((-3, 0, 0)), Orientations::x())]
+#[case(Vector::of((0, 2, 0)), Orientations::y())]
+#[case(Vector::of((0, 0, -1)), Orientations::z())]
+fn orientation_from_vector_differs_from_axis_of(
+    #[case] vector: Vector,
+    #[case] axis: Box<dyn Orientation>,
+) {
+    // when
+    let exact = <dyn Orientation>::from_vector(&vector);
+    let classified = Orientations::axis_of(&vector);
+
+    // then
+    assert_eq!(exact.vector(), vector);
+    assert_ne!(&exact, &axis);
+    assert_eq!(classified, Some(axis));
+}
+
+#[test]
+fn orientation_from_vector_custom() {
+    // given
+    let vector = Vector::of((2, -1, 0));
+
+    // when
+    let result = <dyn Orientation>::from_vector(&vector);
+
+    // then
+    let location = Location::at((1, 1, 1));
+    assert_eq!(result.vector(), vector);
+    assert_ne!(&result, &Orientations::x());
+    assert_eq!(&result, &<dyn Orientation>::from_vector(&vector));
+    assert_eq!(result.go(&location, 2), Location::at((5, -1, 1)));
+    assert_eq!(result.go(&location, -1), Location::at((-1, 2, 1)));
+}
+
+#[rstest]
+#[case(Distance::of((0, 0, 0)), true)]
+#[case(Distance::of((2, 1, 0)), true)]
+#[case(Distance::of((6, 3, 0)), true)]
+#[case(Distance::of((2, 0, 0)), false)]
+#[case(Distance::of((4, 1, 0)), false)]
+#[case(Distance::of((3, 1, 0)), false)]
+#[case(Distance::of((2, 1, 1)), false)]
+fn orientation_from_vector_custom_contains(#[case] distance: Distance, #[case] expected: bool) {
+    // given
+    let orientation = <dyn Orientation>::from_vector(&Vector::of((2, -1, 0)));
+
+    // when
+    let result = orientation.contains(&distance);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+// Orientations end =====
 
+// Topology start =====
+
+#[test]
+fn topology_default() {
     // when
-    let result = start.is_within(&distance, &target);
+    let result = Topology::default();
 
     // then
-    assert_eq!(result, expected);
+    assert_eq!(result, Topology::Bounded);
 }
 
 #[rstest]
-#[case(1, 1, 1, 1, 1, 1, Ordering::Equal)]
-#[case(1, 1, 1, 2, 1, 1, Ordering::Less)]
-#[case(1, 1, 1, 1, 2, 1, Ordering::Less)]
-#[case(1, 1, 1, 1, 1, 2, Ordering::Less)]
-#[case(2, 2, 2, 3, 1, 1, Ordering::Less)]
-fn location_cmp(
-    #[case] lhs_x: i32,
-    #[case] lhs_y: i32,
-    #[case] lhs_z: i32,
-    #[case] rhs_x: i32,
-    #[case] rhs_y: i32,
-    #[case] rhs_z: i32,
-    #[case] expected: Ordering,
+#[case(Topology::Bounded, Location::at((14, 7, 0)), Some(Location::at((14, 7, 0))))]
+#[case(Topology::Bounded, Location::at((15, 7, 0)), None)]
+#[case(Topology::Bounded, Location::at((-1, 7, 0)), None)]
+#[case(Topology::Toroidal, Location::at((14, 7, 0)), Some(Location::at((14, 7, 0))))]
+#[case(Topology::Toroidal, Location::at((15, 7, 0)), Some(Location::at((0, 7, 0))))]
+#[case(Topology::Toroidal, Location::at((-1, 7, 0)), Some(Location::at((14, 7, 0))))]
+#[case(Topology::Toroidal, Location::at((7, 31, 1)), Some(Location::at((7, 1, 0))))]
+fn topology_normalize(
+    #[case] topology: Topology,
+    #[case] location: Location,
+    #[case] expected: Option<Location>,
 ) {
     // given
-    let lhs = Location::at((lhs_x, lhs_y, lhs_z));
-    let rhs = Location::at((rhs_x, rhs_y, rhs_z));
+    let dimension = Dimension::of((15, 15));
 
     // when
-    let result = lhs.cmp(&rhs);
+    let result = topology.normalize(&dimension, &location);
 
     // then
     assert_eq!(result, expected);
 }
 
-// Location end =====
+#[test]
+fn topology_toroidal_go() {
+    // given
+    let dimension = Dimension::of((15, 15));
+    let start = Location::at((14, 7, 0));
 
-// Orientations start =====
+    // when
+    let result = Topology::Toroidal.normalize(&dimension, &Orientations::x().go(&start, 1));
+
+    // then
+    assert_eq!(result, Some(Location::at((0, 7, 0))));
+}
 
 #[rstest]
-#[case(Orientations::x(), Location::at((1, 1, 1)), -1, Location::at((0, 1, 1)))]
-#[case(Orientations::x(), Location::at((1, 1, 1)), 1, Location::at((2, 1, 1)))]
-#[case(Orientations::y(), Location::at((1, 1, 1)), -1, Location::at((1, 0, 1)))]
-#[case(Orientations::y(), Location::at((1, 1, 1)), 1, Location::at((1, 2, 1)))]
-#[case(Orientations::z(), Location::at((1, 1, 1)), -1, Location::at((1, 1, 0)))]
-#[case(Orientations::z(), Location::at((1, 1, 1)), 1, Location::at((1, 1, 2)))]
-fn orientations_go(
-    #[case] orientation: Box<dyn Orientation>,
+#[case(Topology::Bounded, Location::at((14, 7, 0)), Location::at((0, 7, 0)), Distance::of((14, 0, 0)))]
+#[case(Topology::Toroidal, Location::at((14, 7, 0)), Location::at((0, 7, 0)), Distance::of((1, 0, 0)))]
+#[case(Topology::Toroidal, Location::at((0, 0, 0)), Location::at((14, 14, 0)), Distance::of((1, 1, 0)))]
+#[case(Topology::Toroidal, Location::at((3, 7, 0)), Location::at((10, 7, 0)), Distance::of((7, 0, 0)))]
+fn topology_distance(
+    #[case] topology: Topology,
     #[case] start: Location,
-    #[case] amount: i32,
     #[case] end: Location,
+    #[case] expected: Distance,
 ) {
+    // given
+    let dimension = Dimension::of((15, 15));
+
     // when
-    let result = orientation.go(&start, amount);
+    let result = topology.distance(&dimension, &start, &end);
 
     // then
-    assert_eq!(result, end);
+    assert_eq!(result, expected);
 }
 
-#[rstest]
-#[case(Orientations::x(), Distance::of((1, 0, 0)), true)]
-#[case(Orientations::x(), Distance::of((0, 1, 0)), false)]
-#[case(Orientations::x(), Distance::of((0, 0, 1)), false)]
-#[case(Orientations::y(), Distance::of((1, 0, 0)), false)]
-#[case(Orientations::y(), Distance::of((0, 1, 0)), true)]
-#[case(Orientations::y(), Distance::of((0, 0, 1)), false)]
-#[case(Orientations::z(), Distance::of((1, 0, 0)), false)]
-#[case(Orientations::z(), Distance::of((0, 1, 0)), false)]
-#[case(Orientations::z(), Distance::of((0, 0, 1)), true)]
-fn orientations_contains(
-    #[case] orientation: Box<dyn Orientation>,
-    #[case] distance: Distance,
-    #[case] expected: bool,
-) {
+#[test]
+fn topology_toroidal_adjacent() {
+    // given
+    let dimension = Dimension::of((15, 15));
+
     // when
-    let result = orientation.contains(&distance);
+    let result = Topology::Toroidal.distance(
+        &dimension,
+        &Location::at((14, 7, 0)),
+        &Location::at((0, 7, 0)),
+    );
 
     // then
-    assert_eq!(result, expected);
+    assert!(result.is_within(&Distance::of((1, 1, 1))));
 }
 
+// Topology end =====
+
+// Transform start =====
+
 #[rstest]
-#[case(Orientations::x(), Orientations::x(), true)]
-#[case(Orientations::y(), Orientations::y(), true)]
-#[case(Orientations::z(), Orientations::z(), true)]
-#[case(Orientations::x(), Orientations::y(), false)]
-#[case(Orientations::x(), Orientations::z(), false)]
-#[case(Orientations::y(), Orientations::z(), false)]
-fn orientations_eq(
-    #[case] lhs: Box<dyn Orientation>,
-    #[case] rhs: Box<dyn Orientation>,
-    #[case] expected: bool,
+#[case(Transform::identity(), Location::at((1, 2, 3)), Location::at((1, 2, 3)))]
+#[case(Transform::translation(&Vector::of((1, -1, 2))), Location::at((1, 2, 3)), Location::at((2, 1, 5)))]
+#[case(Transform::swap_xy(), Location::at((1, 2, 3)), Location::at((2, 1, 3)))]
+#[case(Transform::swap_xz(), Location::at((1, 2, 3)), Location::at((3, 2, 1)))]
+#[case(Transform::swap_yz(), Location::at((1, 2, 3)), Location::at((1, 3, 2)))]
+#[case(Transform::reflect_x(), Location::at((1, 2, 3)), Location::at((-1, 2, 3)))]
+#[case(Transform::reflect_y(), Location::at((1, 2, 3)), Location::at((1, -2, 3)))]
+#[case(Transform::reflect_z(), Location::at((1, 2, 3)), Location::at((1, 2, -3)))]
+fn transform_apply(
+    #[case] transform: Transform,
+    #[case] location: Location,
+    #[case] expected: Location,
 ) {
     // when
-    let result = lhs == rhs;
+    let result = transform.apply(&location);
 
     // then
     assert_eq!(result, expected);
 }
 
-#[test]
-fn orientations_xy() {
+#[rstest]
+#[case(Location::at((0, 7, 0)), Location::at((14, 7, 0)))]
+#[case(Location::at((14, 0, 0)), Location::at((0, 0, 0)))]
+#[case(Location::at((3, 4, 0)), Location::at((11, 4, 0)))]
+fn transform_then_reflect_translate(#[case] location: Location, #[case] expected: Location) {
+    // given
+    let transform = Transform::reflect_x().then(&Transform::translation(&Vector::of((14, 0, 0))));
+
     // when
-    let result = Orientations::xy();
+    let result = transform.apply(&location);
 
     // then
-    assert_eq!(&result[0], &Orientations::x());
-    assert_eq!(&result[1], &Orientations::y());
+    assert_eq!(result, expected);
 }
 
-#[test]
-fn orientations_xyz() {
+#[rstest]
+#[case(Location::at((0, 0, 0)))]
+#[case(Location::at((1, 2, 3)))]
+#[case(Location::at((-4, 5, -6)))]
+fn transform_then_matches_sequential(#[case] location: Location) {
+    // given
+    let first = Transform::translation(&Vector::of((1, 2, 3)));
+    let second = Transform::swap_xy();
+    let third = Transform::reflect_z();
+
     // when
-    let result = Orientations::xyz();
+    let result = first.then(&second).then(&third).apply(&location);
 
     // then
-    assert_eq!(&result[0], &Orientations::x());
-    assert_eq!(&result[1], &Orientations::y());
-    assert_eq!(&result[2], &Orientations::z());
+    assert_eq!(result, third.apply(&second.apply(&first.apply(&location))));
 }
 
-// Orientations end =====
+// Transform end =====
 
 // Vector start =====
 
@@ -635,6 +2170,24 @@ fn vector_of_xyz() {
     assert_eq!(result.z(), z);
 }
 
+#[test]
+fn vector_from_xy() {
+    // when
+    let result: Vector = (1, -2).into();
+
+    // then
+    assert_eq!(result, Vector::of((1, -2, 0)));
+}
+
+#[test]
+fn vector_from_xyz() {
+    // when
+    let result: Vector = (1, -2, 3).into();
+
+    // then
+    assert_eq!(result, Vector::of((1, -2, 3)));
+}
+
 #[rstest]
 #[case(1, 1, 1, 2, 1, 1)]
 #[case(1, 1, 1, 1, 2, 1)]
@@ -691,4 +2244,282 @@ fn vector_cmp(
     assert_eq!(result, expected);
 }
 
+#[rstest]
+#[case(Vector::of((1, 5, 0)), Vector::of((3, 2, 0)), Vector::of((1, 2, 0)))]
+#[case(Vector::of((-1, 2, 3)), Vector::of((1, -2, 3)), Vector::of((-1, -2, 3)))]
+fn vector_component_min(#[case] lhs: Vector, #[case] rhs: Vector, #[case] expected: Vector) {
+    // when
+    let result = lhs.component_min(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Vector::of((1, 5, 0)), Vector::of((3, 2, 0)), Vector::of((3, 5, 0)))]
+#[case(Vector::of((-1, 2, 3)), Vector::of((1, -2, 3)), Vector::of((1, 2, 3)))]
+fn vector_component_max(#[case] lhs: Vector, #[case] rhs: Vector, #[case] expected: Vector) {
+    // when
+    let result = lhs.component_max(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(1, 2, 3, 4, 5, 6)]
+#[case(-1, -2, -3, 1, 2, 3)]
+#[case(-4, 0, 2, 3, -5, -2)]
+#[case(0, 0, 0, 0, 0, 0)]
+fn vector_add(
+    #[case] lhs_x: i32,
+    #[case] lhs_y: i32,
+    #[case] lhs_z: i32,
+    #[case] rhs_x: i32,
+    #[case] rhs_y: i32,
+    #[case] rhs_z: i32,
+) {
+    // given
+    let lhs = Vector::of((lhs_x, lhs_y, lhs_z));
+    let rhs = Vector::of((rhs_x, rhs_y, rhs_z));
+
+    // when
+    let result = lhs + rhs;
+
+    // then
+    assert_eq!(
+        result,
+        Vector::of((lhs_x + rhs_x, lhs_y + rhs_y, lhs_z + rhs_z))
+    );
+    assert_eq!(result, rhs + lhs);
+    assert_eq!(result, Add::add(&lhs, &rhs));
+}
+
+#[rstest]
+#[case(1, 2, 3, 4, 5, 6)]
+#[case(-1, -2, -3, 1, 2, 3)]
+#[case(-4, 0, 2, 3, -5, -2)]
+#[case(0, 0, 0, 0, 0, 0)]
+fn vector_sub(
+    #[case] lhs_x: i32,
+    #[case] lhs_y: i32,
+    #[case] lhs_z: i32,
+    #[case] rhs_x: i32,
+    #[case] rhs_y: i32,
+    #[case] rhs_z: i32,
+) {
+    // given
+    let lhs = Vector::of((lhs_x, lhs_y, lhs_z));
+    let rhs = Vector::of((rhs_x, rhs_y, rhs_z));
+
+    // when
+    let result = lhs - rhs;
+
+    // then
+    assert_eq!(
+        result,
+        Vector::of((lhs_x - rhs_x, lhs_y - rhs_y, lhs_z - rhs_z))
+    );
+    assert_eq!(result, Sub::sub(&lhs, &rhs));
+    assert_eq!(result + rhs, lhs);
+}
+
+#[rstest]
+#[case(Vector::of((1, -2, 3)), 0, Vector::of((0, 0, 0)))]
+#[case(Vector::of((1, -2, 3)), 1, Vector::of((1, -2, 3)))]
+#[case(Vector::of((1, -2, 3)), 3, Vector::of((3, -6, 9)))]
+#[case(Vector::of((1, -2, 3)), -2, Vector::of((-2, 4, -6)))]
+fn vector_mul(#[case] vector: Vector, #[case] scalar: i32, #[case] expected: Vector) {
+    // when
+    let result = vector * scalar;
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(scalar * vector, expected);
+}
+
+#[test]
+fn vector_mul_go() {
+    // given
+    let location = Location::at((1, 1, 0));
+    let direction = Vector::of((1, 0, 0));
+
+    // when
+    let result = location.go(&(direction * 3));
+
+    // then
+    assert_eq!(result, Orientations::x().go(&location, 3));
+}
+
+#[rstest]
+#[case(Vector::of((0, 0, 0)), Vector::of((0, 0, 0)))]
+#[case(Vector::of((1, 0, 0)), Vector::of((-1, 0, 0)))]
+#[case(Vector::of((1, -2, 3)), Vector::of((-1, 2, -3)))]
+fn vector_neg(#[case] vector: Vector, #[case] expected: Vector) {
+    // when
+    let result = -vector;
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(-result, vector);
+    assert_eq!(result + vector, Vector::of((0, 0, 0)));
+}
+
+#[test]
+fn vector_neg_go_backward() {
+    // given
+    let direction = Vector::of((1, 0, 0));
+    let end = Location::at((5, 2, 0));
+
+    // when
+    let result = end.go(&-direction);
+
+    // then
+    assert_eq!(result, Location::at((4, 2, 0)));
+    assert_eq!(result.go(&direction), end);
+}
+
+#[rstest]
+#[case(Vector::of((0, 0, 0)), 0.0)]
+#[case(Vector::of((3, 4, 0)), 5.0)]
+#[case(Vector::of((-3, 0, -4)), 5.0)]
+#[case(Vector::of((0, -7, 0)), 7.0)]
+#[case(Vector::of((1, 1, 1)), 3f64.sqrt())]
+fn vector_magnitude(#[case] vector: Vector, #[case] expected: f64) {
+    // when
+    let result = vector.magnitude();
+
+    // then
+    assert!((result - expected).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(Vector::of((0, 0, 0)), 0)]
+#[case(Vector::of((3, 4, 0)), 7)]
+#[case(Vector::of((-3, 0, -4)), 7)]
+#[case(Vector::of((0, -7, 0)), 7)]
+#[case(Vector::of((1, 1, 1)), 3)]
+fn vector_manhattan_length(#[case] vector: Vector, #[case] expected: i32) {
+    // when
+    let result = vector.manhattan_length();
+
+    // then
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(Vector::of((1, 0, 0)), Vector::of((0, 1, 0)), 0)]
+#[case(Vector::of((1, 0, 0)), Vector::of((0, 0, 1)), 0)]
+#[case(Vector::of((0, 1, 0)), Vector::of((0, 0, 1)), 0)]
+#[case(Vector::of((1, 1, 0)), Vector::of((1, -1, 0)), 0)]
+#[case(Vector::of((2, 0, 0)), Vector::of((3, 0, 0)), 6)]
+#[case(Vector::of((1, 2, 3)), Vector::of((4, -5, 6)), 12)]
+fn vector_dot(#[case] lhs: Vector, #[case] rhs: Vector, #[case] expected: i32) {
+    // when
+    let result = lhs.dot(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(rhs.dot(&lhs), expected);
+}
+
+#[rstest]
+#[case(Vector::of((1, 0, 0)), Vector::of((0, 1, 0)), Vector::of((0, 0, 1)))]
+#[case(Vector::of((0, 1, 0)), Vector::of((0, 0, 1)), Vector::of((1, 0, 0)))]
+#[case(Vector::of((0, 0, 1)), Vector::of((1, 0, 0)), Vector::of((0, 1, 0)))]
+#[case(Vector::of((0, 1, 0)), Vector::of((1, 0, 0)), Vector::of((0, 0, -1)))]
+#[case(Vector::of((2, 2, 2)), Vector::of((1, 1, 1)), Vector::of((0, 0, 0)))]
+#[case(Vector::of((1, 2, 3)), Vector::of((4, 5, 6)), Vector::of((-3, 6, -3)))]
+fn vector_cross(#[case] lhs: Vector, #[case] rhs: Vector, #[case] expected: Vector) {
+    // when
+    let result = lhs.cross(&rhs);
+
+    // then
+    assert_eq!(result, expected);
+    assert_eq!(result.dot(&lhs), 0);
+    assert_eq!(result.dot(&rhs), 0);
+}
+
 // Vector end =====
+
+// Serde start =====
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case(Dimension::of((15, 15)))]
+#[case(Dimension::of((7, 5, 3)))]
+#[case(Dimension::of((4096, 4096, 1)))]
+fn dimension_serde_round_trip(#[case] dimension: Dimension) {
+    // when
+    let json = serde_json::to_string(&dimension).unwrap();
+    let result: Dimension = serde_json::from_str(&json).unwrap();
+
+    // then
+    assert_eq!(result, dimension);
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case(r#"{"width":0,"height":15,"depth":1}"#)]
+#[case(r#"{"width":15,"height":0,"depth":1}"#)]
+#[case(r#"{"width":15,"height":15,"depth":0}"#)]
+#[case(r#"{"width":4096,"height":4096,"depth":2}"#)]
+#[case(r#"{"width":4294967295,"height":4294967295,"depth":4294967295}"#)]
+fn dimension_serde_invalid(#[case] json: &str) {
+    // when
+    let result = serde_json::from_str::<Dimension>(json);
+
+    // then
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn location_serde_format() {
+    // when
+    let result = serde_json::to_string(&Location::at((1, -2, 3))).unwrap();
+
+    // then
+    assert_eq!(result, r#"{"x":1,"y":-2,"z":3}"#);
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case(Location::at((1, -2, 3)))]
+#[case(Location::at((0, 0, 0)))]
+fn location_serde_round_trip(#[case] location: Location) {
+    // when
+    let json = serde_json::to_string(&location).unwrap();
+    let result: Location = serde_json::from_str(&json).unwrap();
+
+    // then
+    assert_eq!(result, location);
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case(Vector::of((1, -2, 3)))]
+#[case(Vector::of((0, 0, 0)))]
+fn vector_serde_round_trip(#[case] vector: Vector) {
+    // when
+    let json = serde_json::to_string(&vector).unwrap();
+    let result: Vector = serde_json::from_str(&json).unwrap();
+
+    // then
+    assert_eq!(result, vector);
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+#[case(Distance::of((1, 2, 3)))]
+#[case(*Distance::max())]
+fn distance_serde_round_trip(#[case] distance: Distance) {
+    // when
+    let json = serde_json::to_string(&distance).unwrap();
+    let result: Distance = serde_json::from_str(&json).unwrap();
+
+    // then
+    assert_eq!(result, distance);
+}
+
+// Serde end =====